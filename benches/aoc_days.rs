@@ -1,183 +1,39 @@
-use aoc_2025::days;
+use aoc_2025::{days, input};
 use criterion::{Criterion, criterion_group, criterion_main};
-use std::hint::black_box;
+use std::{hint::black_box, path::Path};
 
+/// Benchmark every registered solver, grouping the variants of
+/// each part so they can be compared directly.
+///
+/// Inputs are read from the inputs directory at runtime, and days
+/// without one are skipped.
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("1 p1", |b| {
-        let input = include_str!("../inputs/one.txt");
-        b.iter(|| {
-            let result = days::one::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("1 p2", |b| {
-        let input = include_str!("../inputs/one.txt");
-        b.iter(|| {
-            let result = days::one::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("2 p1", |b| {
-        let input = include_str!("../inputs/two.txt");
-        b.iter(|| {
-            let result = days::two::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("2 p2", |b| {
-        let input = include_str!("../inputs/two.txt");
-        b.iter(|| {
-            let result = days::two::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("3 p1", |b| {
-        let input = include_str!("../inputs/three.txt");
-        b.iter(|| {
-            let result = days::three::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("3 p2", |b| {
-        let input = include_str!("../inputs/three.txt");
-        b.iter(|| {
-            let result = days::three::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("4 p1", |b| {
-        let input = include_str!("../inputs/four.txt");
-        b.iter(|| {
-            let result = days::four::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("4 p2", |b| {
-        let input = include_str!("../inputs/four.txt");
-        b.iter(|| {
-            let result = days::four::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("5 p1", |b| {
-        let input = include_str!("../inputs/five.txt");
-        b.iter(|| {
-            let result = days::five::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("5 p2", |b| {
-        let input = include_str!("../inputs/five.txt");
-        b.iter(|| {
-            let result = days::five::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("6 p1", |b| {
-        let input = include_str!("../inputs/six.txt");
-        b.iter(|| {
-            let result = days::six::first_try::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("6 p1 second try", |b| {
-        let input = include_str!("../inputs/six.txt");
-        b.iter(|| {
-            let result = days::six::second_try::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("6 p2", |b| {
-        let input = include_str!("../inputs/six.txt");
-        b.iter(|| {
-            let result = days::six::first_try::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("6 p2 second try", |b| {
-        let input = include_str!("../inputs/six.txt");
-        b.iter(|| {
-            let result = days::six::second_try::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("7 p1", |b| {
-        let input = include_str!("../inputs/seven.txt");
-        b.iter(|| {
-            let result = days::seven::first_try::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("7 p2", |b| {
-        let input = include_str!("../inputs/seven.txt");
-        b.iter(|| {
-            let result = days::seven::first_try::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("7 p1 second try", |b| {
-        let input = include_str!("../inputs/seven.txt");
-        b.iter(|| {
-            let result = days::seven::second_try::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("7 p2 second try", |b| {
-        let input = include_str!("../inputs/seven.txt");
-        b.iter(|| {
-            let result = days::seven::second_try::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("8 p1", |b| {
-        let input = include_str!("../inputs/eight.txt");
-        b.iter(|| {
-            let result = days::eight::part_one(input, 1000);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("8 p2", |b| {
-        let input = include_str!("../inputs/eight.txt");
-        b.iter(|| {
-            let result = days::eight::part_two(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("11 p1", |b| {
-        let input = include_str!("../inputs/eleven.txt");
-        b.iter(|| {
-            let result = days::eleven::part_one(input);
-            black_box(result);
-        })
-    });
-
-    c.bench_function("11 p2", |b| {
-        let input = include_str!("../inputs/eleven.txt");
-        b.iter(|| {
-            let result = days::eleven::part_two(input);
-            black_box(result);
-        })
-    });
+    let registry = days::registry();
+
+    for (i, solution) in registry.iter().enumerate() {
+        let (day, part) = (solution.day(), solution.part());
+        if i > 0 && (registry[i - 1].day(), registry[i - 1].part()) == (day, part) {
+            continue;
+        }
+
+        let input = match input::find(Path::new(input::DEFAULT_DIR), day, None)
+            .and_then(|path| input::read(&path))
+        {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {day} part {part}: {e}");
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("{day} p{part}"));
+        for variant in days::variants(day, part) {
+            group.bench_function(variant.variant(), |b| {
+                b.iter(|| black_box(variant.solve(black_box(&input))))
+            });
+        }
+        group.finish();
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
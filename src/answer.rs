@@ -0,0 +1,224 @@
+use crate::json::Value;
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+/// The value produced by solving one part of a day.
+///
+/// Integers are equal if they have the same value, whichever
+/// variant holds them, but never equal text.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    /// An integer too large for 128 bits, as decimal digits with an
+    /// optional leading `-`. [`Answer::parse`] builds these.
+    Big(String),
+    Text(String),
+}
+
+impl Answer {
+    /// Read an answer written out as text, like in an answers file,
+    /// or typed in by hand.
+    ///
+    /// Anything that looks like an integer becomes one, using the
+    /// smallest variant it fits in. Everything else is text.
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Self::Text(text.to_string());
+        }
+
+        if let Ok(value) = text.parse::<u128>() {
+            return Self::Unsigned(value);
+        }
+        if let Ok(value) = text.parse::<i128>() {
+            return Self::Signed(value);
+        }
+
+        let digits = digits.trim_start_matches('0');
+        match text.starts_with('-') {
+            true => Self::Big(format!("-{digits}")),
+            false => Self::Big(digits.to_string()),
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, Self::Text(_))
+    }
+
+    /// The answer as an `i128`, if it's an integer that fits.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Self::Signed(value) => Some(*value),
+            Self::Unsigned(value) => (*value).try_into().ok(),
+            Self::Big(_) | Self::Text(_) => None,
+        }
+    }
+
+    /// The answer as a `u128`, if it's a non-negative integer that fits.
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            Self::Signed(value) => (*value).try_into().ok(),
+            Self::Unsigned(value) => Some(*value),
+            Self::Big(_) | Self::Text(_) => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Signed(value) => write!(f, "{value}"),
+            Self::Unsigned(value) => write!(f, "{value}"),
+            Self::Big(digits) | Self::Text(digits) => f.write_str(digits),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::Text(_), _) | (_, Self::Text(_)) => false,
+            (Self::Big(a), Self::Big(b)) => a == b,
+            // big integers never fit in 128 bits
+            (Self::Big(_), _) | (_, Self::Big(_)) => false,
+            _ => self.as_i128() == other.as_i128() && self.as_u128() == other.as_u128(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.is_integer().hash(state);
+        self.to_string().hash(state);
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident, $as:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.$as() == Some((*other).into())
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(Unsigned, as_u128: u8, u16, u32, u64, u128);
+from_integer!(Signed, as_i128: i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Self::Signed(value as i128)
+    }
+}
+
+impl PartialEq<usize> for Answer {
+    fn eq(&self, other: &usize) -> bool {
+        self.as_u128() == Some(*other as u128)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_text() == Some(*other)
+    }
+}
+
+impl From<&Answer> for Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Text(text) => Value::String(text.clone()),
+            // JSON doesn't limit the size of numbers, though readers might
+            integer => Value::Number(integer.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parsed() {
+        assert_eq!(Answer::parse("6616"), Answer::Unsigned(6616));
+        assert_eq!(Answer::parse(" -12 "), Answer::Signed(-12));
+        assert_eq!(Answer::parse("#1"), Answer::Text("#1".into()));
+        assert_eq!(Answer::parse("-"), Answer::Text("-".into()));
+
+        let big = "1".repeat(45);
+        assert!(
+            matches!(Answer::parse(&format!("000{big}")), Answer::Big(digits) if digits == big)
+        );
+        assert_eq!(
+            Answer::parse(&format!("-{big}")).to_string(),
+            format!("-{big}")
+        );
+    }
+
+    #[test]
+    fn compared() {
+        assert_eq!(Answer::Signed(40), Answer::Unsigned(40));
+        assert_eq!(Answer::from(40u8), 40);
+        assert_eq!(Answer::from(-3i64), -3);
+        assert_eq!(Answer::from(7usize), 7usize);
+        assert_ne!(Answer::from("40"), Answer::from(40));
+        assert_eq!(Answer::from("ABC"), "ABC");
+
+        assert_ne!(Answer::Unsigned(u128::MAX), Answer::Unsigned(u128::MAX - 1));
+        assert_ne!(Answer::Signed(-1), Answer::Unsigned(u128::MAX));
+        let big = "9".repeat(40);
+        assert_eq!(Answer::parse(&big), Answer::parse(&format!("0{big}")));
+        assert_ne!(Answer::parse(&big), Answer::Unsigned(u128::MAX));
+
+        assert_eq!(Answer::Signed(-1).as_u128(), None);
+        assert_eq!(Answer::Unsigned(u128::MAX).as_i128(), None);
+        assert_eq!(Answer::Signed(5).as_u128(), Some(5));
+    }
+
+    #[test]
+    fn serialized() {
+        assert_eq!(Value::from(&Answer::from(12u64)).to_string(), "12");
+        assert_eq!(Value::from(&Answer::from(-12)).to_string(), "-12");
+        assert_eq!(Value::from(&Answer::from("a\"b")).to_string(), r#""a\"b""#);
+    }
+}
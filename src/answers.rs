@@ -0,0 +1,202 @@
+use crate::{Answer, Error, days::Part};
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+/// Accepted answers, keyed by day and part.
+///
+/// These are stored in a small subset of TOML:
+///
+/// ```toml
+/// [day1]
+/// part1 = 1092
+/// part2 = 6616
+/// ```
+///
+/// Answers that aren't integers are quoted.
+#[derive(Debug, Default, Clone)]
+pub struct Answers {
+    entries: BTreeMap<(usize, Part), Answer>,
+}
+
+/// How a computed answer compares to the accepted one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+impl Answers {
+    /// Load answers from `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut answers = Self::default();
+        let mut day = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let invalid = || Error::InvalidAnswers {
+                line: i + 1,
+                text: line.to_string(),
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let number = header
+                    .strip_suffix(']')
+                    .and_then(|h| h.strip_prefix("day"))
+                    .ok_or_else(invalid)?;
+                day = Some(number.parse().map_err(|_| invalid())?);
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(invalid()),
+            };
+
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => {
+                    let (value, rest) = quoted.split_once('"').ok_or_else(invalid)?;
+                    let rest = rest.trim();
+                    if !(rest.is_empty() || rest.starts_with('#')) {
+                        return Err(invalid());
+                    }
+                    value
+                }
+                // bare values end at a trailing comment
+                None => value.split('#').next().unwrap_or_default().trim(),
+            };
+
+            answers.insert(day.ok_or_else(invalid)?, part, Answer::parse(value));
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        std::fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&Answer> {
+        self.entries.get(&(day, part))
+    }
+
+    /// Store an answer, returning the one it replaced.
+    pub fn insert(&mut self, day: usize, part: Part, answer: Answer) -> Option<Answer> {
+        self.entries.insert((day, part), answer)
+    }
+
+    pub fn check(&self, day: usize, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut last_day = None;
+        for ((day, part), answer) in &self.entries {
+            if last_day != Some(*day) {
+                if last_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{day}]")?;
+                last_day = Some(*day);
+            }
+
+            match answer {
+                Answer::Text(text) => writeln!(f, "part{part} = \"{text}\"")?,
+                integer => writeln!(f, "part{part} = {integer}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Self::Unknown => write!(f, "unchecked"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let text = "[day1]\npart1 = 1092\npart2 = \"6616\"\n\n[day11]\npart1 = \"ab c\"\n";
+        let answers = Answers::parse(text).unwrap();
+
+        assert_eq!(answers.get(1, Part::Two).unwrap(), &Answer::from(6616));
+        assert_eq!(answers.get(11, Part::One).unwrap(), &Answer::from("ab c"));
+        assert!(answers.get(11, Part::Two).is_none());
+        assert_eq!(answers.to_string(), text.replace("\"6616\"", "6616"));
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers =
+            Answers::parse("# comment\n[day2]\npart1 = 40 # bare\npart2 = \"#1\"").unwrap();
+
+        assert_eq!(
+            answers.check(2, Part::One, &Answer::from(40)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(2, Part::One, &Answer::from(41)),
+            Verdict::Wrong {
+                expected: Answer::from(40)
+            }
+        );
+        assert_eq!(
+            answers.check(2, Part::Two, &Answer::from("#1")),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(3, Part::One, &Answer::from(40)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            Answers::parse("part1 = \"1\""),
+            Err(Error::InvalidAnswers { line: 1, .. })
+        ));
+        assert!(matches!(
+            Answers::parse("[day1]\n\npart3 = \"1\""),
+            Err(Error::InvalidAnswers { line: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,881 @@
+use crate::{
+    Error,
+    answers::{Answers, Verdict},
+    client,
+    days::{self, Part, one},
+    input, readme, runner, scaffold,
+    submissions::{Reply, Submissions},
+    watch::{self, Watcher},
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Solutions for Advent of Code 2025!
+#[derive(clap::Parser)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The advent of code day.
+    #[arg(required_unless_present = "list")]
+    day: Option<usize>,
+    /// The path to the advent of code input data, or `-` for stdin.
+    ///
+    /// If omitted, the input is found in the inputs directory.
+    input: Option<PathBuf>,
+    /// The directory searched for inputs, named like `one.txt`.
+    #[arg(long, default_value = input::DEFAULT_DIR)]
+    inputs: PathBuf,
+    /// Use the day's example input, like `one_test.txt`, or a part's
+    /// own example, like `eleven_test2.txt`, where it has one.
+    #[arg(long, short, conflicts_with = "input")]
+    example: bool,
+    /// Which part of the day to solve.
+    #[arg(long, short, value_enum, default_value = "both")]
+    part: PartSelection,
+    /// Run a specific implementation instead of the preferred one.
+    #[arg(long, short)]
+    variant: Option<String>,
+    /// List every available day, part and variant.
+    #[arg(long, exclusive = true)]
+    list: bool,
+    /// Place the output into the clipboard.
+    ///
+    /// When solving both parts, the last answer is copied.
+    #[arg(long, short)]
+    clipboard: bool,
+    /// How to print results.
+    #[arg(long, value_enum, default_value = "text")]
+    format: Format,
+    /// Solve again whenever the input changes, showing how the answers changed.
+    #[arg(long, short, conflicts_with_all = ["clipboard", "format", "check", "record"])]
+    watch: bool,
+    /// Whenever the day's source changes, rebuild, run its tests and
+    /// solve its example, showing how the answers changed.
+    #[arg(long, conflicts_with_all = ["input", "example", "watch", "clipboard", "format", "check", "record"])]
+    watch_tests: bool,
+    #[command(flatten)]
+    verification: Verification,
+    /// Show solver progress on stderr. Repeat for more detail.
+    #[arg(long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(clap::Args)]
+struct Verification {
+    /// Compare answers against the accepted ones, failing on any
+    /// mismatch, or any solver that errors or times out.
+    #[arg(long)]
+    check: bool,
+    /// Save answers as accepted.
+    #[arg(long, conflicts_with = "check")]
+    record: bool,
+    /// The file holding accepted answers.
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+impl Verification {
+    fn load(&self) -> Result<Option<Answers>, Error> {
+        if !self.check && !self.record {
+            return Ok(None);
+        }
+
+        Answers::load(&self.answers).map(Some)
+    }
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Run every registered day, printing a table of answers and timings.
+    RunAll {
+        /// The directory containing each day's input, like `one.txt`.
+        #[arg(long, default_value = input::DEFAULT_DIR)]
+        inputs: PathBuf,
+        /// Use each day's example input, like `one_test.txt`, or a
+        /// part's own example, like `eleven_test2.txt`.
+        #[arg(long, short)]
+        example: bool,
+        #[command(flatten)]
+        timing: Timing,
+        /// Run every variant rather than just the preferred ones.
+        ///
+        /// When recording, only answers that aren't yet stored are saved.
+        #[arg(long, short)]
+        all_variants: bool,
+        /// How to print results.
+        ///
+        /// JSON is printed as one object per solver, one per line.
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+        #[command(flatten)]
+        verification: Verification,
+    },
+    /// Download a day's input into the inputs directory.
+    ///
+    /// Inputs that are already there are never downloaded again.
+    /// The session token is read from `AOC_SESSION`, or else from
+    /// `.session` here or `aoc/session` in `$XDG_CONFIG_HOME`,
+    /// which defaults to `~/.config`.
+    Fetch {
+        /// The advent of code day.
+        day: usize,
+        /// The directory inputs are saved in, named like `one.txt`.
+        #[arg(long, default_value = input::DEFAULT_DIR)]
+        inputs: PathBuf,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Solve a part and submit the answer.
+    ///
+    /// Accepted answers are recorded. Rejected answers are remembered
+    /// so they're never submitted twice, along with any wait the
+    /// server asks for.
+    Submit(Submission),
+    /// Count day one's rotations on a dial other than the safe's.
+    Dial(DialSettings),
+    /// Create a new day's module, registering it and its example input.
+    ///
+    /// Existing modules are never overwritten.
+    New {
+        /// The advent of code day.
+        day: usize,
+    },
+    /// Measure every variant and rewrite the timing table in the README.
+    ///
+    /// The table is placed between `<!-- timings:start -->` and
+    /// `<!-- timings:end -->`. Solve times in the `Time` column are kept.
+    Readme {
+        /// The README to update.
+        #[arg(long, default_value = "README.md")]
+        readme: PathBuf,
+        /// The directory containing each day's input, like `one.txt`.
+        #[arg(long, default_value = input::DEFAULT_DIR)]
+        inputs: PathBuf,
+        #[command(flatten)]
+        timing: Timing,
+    },
+}
+
+#[derive(clap::Args)]
+struct Timing {
+    /// Untimed runs before measuring each solver.
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+    /// Timed runs per solver. The median is reported.
+    #[arg(long, short = 'n', default_value_t = 5)]
+    repetitions: usize,
+    /// Seconds a single run may take before the solver is abandoned, or 0 for no limit.
+    #[arg(long, default_value_t = 60.0)]
+    timeout: f64,
+}
+
+impl Timing {
+    fn options(&self) -> runner::Options {
+        runner::Options {
+            warmup: self.warmup,
+            repetitions: self.repetitions,
+            timeout: Some(Duration::from_secs_f64(self.timeout.max(0.0)))
+                .filter(|timeout| !timeout.is_zero()),
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct Submission {
+    /// The advent of code day.
+    day: usize,
+    /// The part to submit.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// The path to the input data, or `-` for stdin.
+    ///
+    /// If omitted, the input is found in the inputs directory.
+    input: Option<PathBuf>,
+    /// The directory searched for inputs, named like `one.txt`.
+    #[arg(long, default_value = input::DEFAULT_DIR)]
+    inputs: PathBuf,
+    /// Submit a specific implementation's answer instead of the preferred one.
+    #[arg(long, short)]
+    variant: Option<String>,
+    /// The file holding accepted answers.
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+    /// The file holding rejected answers and the current wait.
+    #[arg(long, default_value = "submissions.toml")]
+    submissions: PathBuf,
+    /// Sleep through any wait instead of giving up.
+    #[arg(long)]
+    wait: bool,
+    #[command(flatten)]
+    server: Server,
+}
+
+#[derive(clap::Args)]
+struct DialSettings {
+    /// The path to the rotations, or `-` for stdin.
+    ///
+    /// If omitted, day one's input is found in the inputs directory.
+    input: Option<PathBuf>,
+    /// The directory searched for inputs, named like `one.txt`.
+    #[arg(long, default_value = input::DEFAULT_DIR)]
+    inputs: PathBuf,
+    /// Use day one's example input.
+    #[arg(long, short, conflicts_with = "input")]
+    example: bool,
+    /// Count landing on a target (1), passing one too (2), or both.
+    #[arg(long, short, value_enum, default_value = "both")]
+    part: PartSelection,
+    /// How many positions the dial has, numbered from 0.
+    #[arg(long, default_value_t = 100)]
+    size: u64,
+    /// The position the dial starts at.
+    #[arg(long, default_value_t = 50)]
+    start: u64,
+    /// A position to count. Repeat it to count several.
+    #[arg(long = "target", default_values_t = [0])]
+    targets: Vec<u64>,
+    /// Print what each rotation did, instead of the counts.
+    #[arg(long, value_enum, conflicts_with = "part")]
+    trace: Option<TraceFormat>,
+    /// Check each rotation against turning the dial a click at a time,
+    /// failing if any are counted differently.
+    ///
+    /// This is slow for large rotations.
+    #[arg(long)]
+    check: bool,
+    /// Fail on blank lines and `\r\n` line endings, rather than
+    /// only on lines that aren't rotations.
+    #[arg(long)]
+    strict: bool,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum TraceFormat {
+    /// Columns lined up for people to read.
+    Table,
+    Csv,
+}
+
+#[derive(clap::Args)]
+struct Server {
+    /// The server to talk to, for anything that mimics Advent of Code.
+    #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Read the session token from this file instead.
+    #[arg(long)]
+    session_file: Option<PathBuf>,
+}
+
+impl Server {
+    fn connect(&self) -> Result<client::Client, Error> {
+        let files = match &self.session_file {
+            Some(file) => vec![file.clone()],
+            None => client::session_files(),
+        };
+
+        Ok(client::Client::new(
+            &self.base_url,
+            client::session(&files)?,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    /// Answers for people to read.
+    Text,
+    /// One JSON object per solution, with timing and input details.
+    Json,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            Self::One => &[Part::One],
+            Self::Two => &[Part::Two],
+            Self::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+impl Args {
+    /// Read the input from the given path, or find it by the day's name.
+    ///
+    /// Examples can differ between parts, so the part is needed too.
+    fn get_input(&self, day: usize, part: Part) -> Result<(String, input::Source), Error> {
+        let example = self.example.then_some(part);
+        input::load(self.input.as_deref(), &self.inputs, day, example)
+    }
+
+    /// Pick the solvers for the selected day and parts.
+    ///
+    /// When solving both parts with a named variant, parts
+    /// without that variant are skipped.
+    fn solutions(&self, day: usize) -> Result<Vec<&'static dyn days::Solution>, Error> {
+        let variant = self.variant.as_deref();
+        let (found, missing): (Vec<_>, Vec<_>) = self
+            .part
+            .parts()
+            .iter()
+            .map(|part| days::find(day, *part, variant))
+            .partition(Result::is_ok);
+
+        let skip_missing = variant.is_some() && !found.is_empty();
+        if let Some(Err(e)) = missing.into_iter().next()
+            && !skip_missing
+        {
+            return Err(e);
+        }
+
+        Ok(found.into_iter().flatten().collect())
+    }
+}
+
+/// Print every registered solver, one line per day and part.
+///
+/// The first variant listed is the one used by default.
+fn list_solutions() {
+    let registry = days::registry();
+
+    for (i, solution) in registry.iter().enumerate() {
+        let (day, part) = (solution.day(), solution.part());
+        if i > 0 && (registry[i - 1].day(), registry[i - 1].part()) == (day, part) {
+            continue;
+        }
+
+        let variants = days::variants(day, part)
+            .map(|s| s.variant())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("Day {day:>2} part {part}: {variants}");
+    }
+}
+
+fn run_all(
+    inputs: &std::path::Path,
+    example: bool,
+    options: &runner::Options,
+    all_variants: bool,
+    format: Format,
+    verification: &Verification,
+) -> Result<ExitCode, Error> {
+    let mut answers = verification.load()?;
+    let reports = runner::run_all(inputs, example, options, all_variants);
+    let checked = answers.as_ref().filter(|_| verification.check);
+
+    match format {
+        Format::Text => println!(
+            "{}",
+            runner::Summary {
+                reports: &reports,
+                answers: checked,
+            }
+        ),
+        Format::Json => {
+            for report in &reports {
+                println!("{}", report.to_json(checked));
+            }
+        }
+    }
+
+    if let Some(answers) = &mut answers
+        && verification.record
+    {
+        for report in &reports {
+            let (day, part) = (report.solution.day(), report.solution.part());
+            if let runner::Outcome::Solved(m) = &report.outcome
+                && answers.get(day, part).is_none()
+            {
+                answers.insert(day, part, m.answer.clone());
+            }
+        }
+
+        answers.save(&verification.answers)?;
+    }
+
+    Ok(match answers {
+        Some(answers) if verification.check && runner::any_failing(&reports, &answers) => {
+            ExitCode::FAILURE
+        }
+        _ => ExitCode::SUCCESS,
+    })
+}
+
+fn fetch(day: usize, inputs: &std::path::Path, server: &Server) -> Result<ExitCode, Error> {
+    let (path, downloaded) = client::fetch(&server.connect()?, inputs, day)?;
+    match downloaded {
+        true => println!("Saved day {day}'s input to {}", path.display()),
+        false => println!("Day {day}'s input is already at {}", path.display()),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(args: &Submission) -> Result<ExitCode, Error> {
+    let Submission {
+        day,
+        part,
+        input,
+        inputs,
+        variant,
+        answers,
+        submissions,
+        wait,
+        server,
+    } = args;
+    let (day, part) = (*day, if *part == 1 { Part::One } else { Part::Two });
+
+    let solution = days::find(day, part, variant.as_deref())?;
+    let (input, _) = input::load(input.as_deref(), inputs, day, None)?;
+    let answer = solution.solve(&input)?;
+    println!("Day {day} part {part}: {answer}");
+
+    let answers_path = answers;
+    let mut answers = Answers::load(answers_path)?;
+    if let Some(accepted) = answers.get(day, part) {
+        if *accepted == answer {
+            println!("This answer was already accepted");
+            return Ok(ExitCode::SUCCESS);
+        }
+        return Err(Error::Refused(format!("`{accepted}` was already accepted")));
+    }
+
+    let submissions_path = submissions;
+    let mut submissions = Submissions::load(submissions_path)?;
+    if let Some(reason) = submissions.known_wrong(day, part, &answer) {
+        return Err(Error::Refused(reason));
+    }
+
+    if let Some(remaining) = submissions.wait(SystemTime::now()) {
+        if !wait {
+            return Err(Error::Refused(format!(
+                "the server asked to wait another {}s, pass `--wait` to sleep until then",
+                remaining.as_secs()
+            )));
+        }
+        eprintln!("Waiting {}s before submitting", remaining.as_secs());
+        std::thread::sleep(remaining);
+    }
+
+    let reply = server.connect()?.submit(day, part, &answer)?;
+    println!("{reply}");
+
+    let now = SystemTime::now();
+    match reply {
+        Reply::Correct => {
+            answers.insert(day, part, answer);
+            answers.save(answers_path)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Reply::Wrong { hint, wait } => {
+            submissions.reject(day, part, answer, hint);
+            if let Some(wait) = wait {
+                submissions.wait_for(now, wait);
+            }
+        }
+        Reply::TooSoon { wait } => submissions.wait_for(now, wait),
+        Reply::AlreadySolved | Reply::Unknown(_) => return Ok(ExitCode::FAILURE),
+    }
+
+    submissions.save(submissions_path)?;
+    Ok(ExitCode::FAILURE)
+}
+
+/// Count day one's rotations on the dial described by `settings`.
+fn count_dial(settings: &DialSettings) -> Result<ExitCode, Error> {
+    let (input, _) = input::load(
+        settings.input.as_deref(),
+        &settings.inputs,
+        1,
+        // day one's parts share an example
+        settings.example.then_some(Part::One),
+    )?;
+    let dial = one::Dial::new(settings.size, settings.start, settings.targets.clone())?;
+    let parsing = match settings.strict {
+        true => one::Parsing::Strict,
+        false => one::Parsing::Lenient,
+    };
+    let rotations = one::rotations(&input, parsing)?;
+
+    let mut mismatched = false;
+    if settings.check {
+        let steps = one::trace(dial.clone(), &rotations);
+        for (step, clicked) in steps.iter().zip(one::replay(dial.clone(), &rotations)) {
+            if *step != clicked {
+                mismatched = true;
+                eprintln!(
+                    "line {}: {} from {} ended at {} passing {} targets, but clicking ends at {} passing {}",
+                    step.line,
+                    step.rotation,
+                    step.start,
+                    step.end,
+                    step.passed,
+                    clicked.end,
+                    clicked.passed
+                );
+            }
+        }
+    }
+
+    match settings.trace {
+        Some(format) => {
+            let steps = one::trace(dial, &rotations);
+            match format {
+                TraceFormat::Table => print!("{}", one::Table(&steps)),
+                TraceFormat::Csv => print!("{}", one::Csv(&steps)),
+            }
+        }
+        None => {
+            for part in settings.part.parts() {
+                let count = match part {
+                    Part::One => one::landings(dial.clone(), &rotations),
+                    Part::Two => one::passes(dial.clone(), &rotations),
+                };
+
+                match settings.part {
+                    PartSelection::Both => println!("Part {part}: {count}"),
+                    _ => println!("{count}"),
+                }
+            }
+        }
+    }
+
+    Ok(if mismatched {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// How often watched files are checked.
+const WATCH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// An answer, compared with the last one seen for its part.
+fn answer_change(
+    previous: &mut HashMap<Part, String>,
+    part: Part,
+    answer: Result<String, String>,
+) -> String {
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(e) => return format!("Part {part}: error: {e}"),
+    };
+
+    match previous.insert(part, answer.clone()) {
+        Some(old) if old == answer => format!("Part {part}: {answer} (unchanged)"),
+        Some(old) => format!("Part {part}: {answer} (was {old})"),
+        None => format!("Part {part}: {answer}"),
+    }
+}
+
+fn watch_day(args: &Args) -> Result<ExitCode, Error> {
+    // `day` is required unless listing
+    let day = args.day.unwrap();
+    let solutions = args.solutions(day)?;
+    let paths = solutions
+        .iter()
+        .map(|solution| match &args.input {
+            Some(path) if path.as_os_str() == "-" => Err(Error::Usage(
+                "stdin can't be watched, give a path instead".into(),
+            )),
+            Some(path) => Ok(path.clone()),
+            None => input::find(&args.inputs, day, args.example.then_some(solution.part())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut watched = paths.clone();
+    watched.dedup();
+    let names: Vec<_> = watched.iter().map(|p| p.display().to_string()).collect();
+
+    let mut previous = HashMap::new();
+    let mut watcher = Watcher::new(watched, WATCH_INTERVAL);
+    loop {
+        for (solution, path) in solutions.iter().zip(&paths) {
+            let answer = input::read(path)
+                .and_then(|input| solution.solve(&input))
+                .map(|a| a.to_string())
+                .map_err(|e| e.to_string());
+            println!("{}", answer_change(&mut previous, solution.part(), answer));
+        }
+
+        eprintln!("Watching {} for changes...", names.join(", "));
+        watcher.wait();
+    }
+}
+
+fn watch_tests(args: &Args) -> Result<ExitCode, Error> {
+    use std::process::{Command, Stdio};
+
+    // `day` is required unless listing
+    let day = args.day.unwrap();
+    let name = days::name(day).ok_or(Error::Unsolved {
+        day,
+        part: Part::One,
+        variant: None,
+    })?;
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let cargo = || {
+        let mut command = Command::new(&cargo);
+        command.current_dir(env!("CARGO_MANIFEST_DIR"));
+        command
+    };
+    let failed = |e| Error::Usage(format!("couldn't run cargo: {e}"));
+
+    let mut previous = HashMap::new();
+    let mut watcher = Watcher::new(watch::sources(day), WATCH_INTERVAL);
+    loop {
+        let tests = cargo()
+            .args(["test", "--quiet", "--lib", &format!("days::{name}::")])
+            .status()
+            .map_err(failed)?;
+        println!(
+            "Day {day} tests {}",
+            if tests.success() { "passed" } else { "failed" }
+        );
+
+        for part in args.part.parts() {
+            let mut run = cargo();
+            run.args(["run", "--quiet", "--", &day.to_string(), "--example"])
+                .args(["--part", &part.to_string()]);
+            if let Some(variant) = &args.variant {
+                run.args(["--variant", variant]);
+            }
+
+            let output = run.stderr(Stdio::inherit()).output().map_err(failed)?;
+            let answer = match output.status.success() {
+                true => Ok(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+                false => Err("failed, see above".to_string()),
+            };
+            println!("{}", answer_change(&mut previous, *part, answer));
+        }
+
+        eprintln!("Watching day {day}'s source for changes...");
+        watcher.wait();
+    }
+}
+
+fn new_day(day: usize) -> Result<ExitCode, Error> {
+    for path in scaffold::new_day(std::path::Path::new(env!("CARGO_MANIFEST_DIR")), day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn update_readme(
+    path: &std::path::Path,
+    inputs: &std::path::Path,
+    options: &runner::Options,
+) -> Result<ExitCode, Error> {
+    let reports = runner::run_all(inputs, false, options, true);
+    println!(
+        "{}",
+        runner::Summary {
+            reports: &reports,
+            answers: None,
+        }
+    );
+
+    readme::update(path, &reports)?;
+    println!("Updated {}", path.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_day(args: &Args) -> Result<ExitCode, Error> {
+    // `day` is required unless listing
+    let day = args.day.unwrap();
+    let solutions = args.solutions(day)?;
+    let verification = &args.verification;
+    let mut answers = verification.load()?;
+    let mut any_wrong = false;
+    let mut any_failed = false;
+
+    let mut result = String::new();
+    for solution in solutions {
+        let (input, source) = args.get_input(day, solution.part())?;
+        let start = Instant::now();
+        let answer = match solution.solve(&input) {
+            Ok(answer) => answer,
+            // still report the failure, so the output is all JSON
+            Err(e) if args.format == Format::Json => {
+                let report = runner::Report {
+                    solution,
+                    source: Some(source),
+                    outcome: runner::Outcome::Failed(e),
+                };
+                println!("{}", report.to_json(None));
+                any_failed = true;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let elapsed = start.elapsed();
+        result = answer.to_string();
+
+        if args.format == Format::Json {
+            let report = runner::Report {
+                solution,
+                source: Some(source.clone()),
+                outcome: runner::Outcome::Solved(runner::Measurement {
+                    answer: answer.clone(),
+                    median: elapsed,
+                }),
+            };
+            println!(
+                "{}",
+                report.to_json(answers.as_ref().filter(|_| verification.check))
+            );
+        }
+
+        let mut line = match args.part {
+            PartSelection::Both => format!("Part {}: {result}", solution.part()),
+            _ => result.clone(),
+        };
+
+        if let Some(answers) = &mut answers {
+            if verification.check {
+                let verdict = answers.check(day, solution.part(), &answer);
+                any_wrong |= matches!(verdict, Verdict::Wrong { .. });
+                line = format!("{line} ({verdict})");
+            } else if let Some(previous) = answers.insert(day, solution.part(), answer)
+                && previous.to_string() != result
+            {
+                eprintln!("Replaced recorded answer `{previous}` with `{result}`");
+            }
+        }
+
+        if args.format == Format::Text && !args.clipboard {
+            println!("{line}");
+        }
+    }
+
+    if let Some(answers) = &answers
+        && verification.record
+    {
+        answers.save(&verification.answers)?;
+    }
+
+    if args.clipboard {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(&result))
+            .map_err(|e| Error::Failed(format!("couldn't copy to the clipboard: {e}")))?;
+    }
+
+    Ok(if any_wrong || any_failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Prints solver progress and traces to stderr.
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", short_target(record.target()), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A log target without the path to the days, so `nine` rather than
+/// `aoc_2025::days::nine`.
+fn short_target(target: &str) -> &str {
+    let days = concat!(env!("CARGO_CRATE_NAME"), "::days::");
+    target.strip_prefix(days).unwrap_or(target)
+}
+
+/// Parse the command line and run it, as the `aoc-2025` binary does.
+pub fn main() -> ExitCode {
+    use clap::Parser;
+    let args = Args::parse();
+
+    let level = match args.verbose {
+        0 => log::LevelFilter::Off,
+        1 => log::LevelFilter::Info,
+        2 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    };
+    if log::set_logger(&StderrLogger).is_ok() {
+        log::set_max_level(level);
+    }
+
+    let result = match &args.command {
+        Some(Command::RunAll {
+            inputs,
+            example,
+            timing,
+            all_variants,
+            format,
+            verification,
+        }) => run_all(
+            inputs,
+            *example,
+            &timing.options(),
+            *all_variants,
+            *format,
+            verification,
+        ),
+        Some(Command::Fetch {
+            day,
+            inputs,
+            server,
+        }) => fetch(*day, inputs, server),
+        Some(Command::Submit(submission)) => submit(submission),
+        Some(Command::Dial(settings)) => count_dial(settings),
+        Some(Command::New { day }) => new_day(*day),
+        Some(Command::Readme {
+            readme,
+            inputs,
+            timing,
+        }) => update_readme(readme, inputs, &timing.options()),
+        None if args.watch => watch_day(&args),
+        None if args.watch_tests => watch_tests(&args),
+        None if args.list => {
+            list_solutions();
+            Ok(ExitCode::SUCCESS)
+        }
+        None => run_day(&args),
+    };
+
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        ExitCode::FAILURE
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn targets() {
+        assert_eq!(short_target(module_path!()), "aoc_2025::cli::test");
+        assert_eq!(short_target("aoc_2025::days::one"), "one");
+        assert_eq!(
+            short_target("aoc_2025::days::six::first_try"),
+            "six::first_try"
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{
+    Answer, Error,
+    days::{self, Part},
+    input,
+    submissions::Reply,
+};
+use std::path::{Path, PathBuf};
+
+/// Where puzzles are normally fetched from.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The event year.
+pub const YEAR: usize = 2025;
+/// The environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = "github.com/CorvusPrudens/aoc-2025";
+
+/// The files searched for a session token when `AOC_SESSION` isn't set.
+pub fn session_files() -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(".session")];
+
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")));
+    if let Some(config) = config {
+        files.push(config.join("aoc").join("session"));
+    }
+
+    files
+}
+
+/// Find the session token in `AOC_SESSION`, or the first of `files` that exists.
+pub fn session(files: &[PathBuf]) -> Result<String, Error> {
+    if let Ok(session) = std::env::var(SESSION_VAR)
+        && !session.trim().is_empty()
+    {
+        return Ok(session.trim().to_string());
+    }
+
+    for file in files {
+        match std::fs::read_to_string(file) {
+            Ok(session) if !session.trim().is_empty() => return Ok(session.trim().to_string()),
+            Ok(_) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(source) => {
+                return Err(Error::Io {
+                    path: file.clone(),
+                    source,
+                });
+            }
+        }
+    }
+
+    Err(Error::MissingSession {
+        searched: files.to_vec(),
+    })
+}
+
+/// A logged-in connection to Advent of Code, or anything that looks like it.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent,
+        }
+    }
+
+    /// The URL of a day's page, followed by `path`.
+    fn url(&self, day: usize, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    /// Download a day's puzzle input.
+    pub fn input(&self, day: usize) -> Result<String, Error> {
+        let url = self.url(day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(&url, response)
+    }
+
+    /// Submit an answer, returning what the server thought of it.
+    pub fn submit(&self, day: usize, part: Part, answer: &Answer) -> Result<Reply, Error> {
+        let url = self.url(day, "/answer");
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([
+                ("level", level.as_str()),
+                ("answer", answer.to_string().as_str()),
+            ]);
+
+        read_response(&url, response).map(|html| Reply::parse(&html))
+    }
+}
+
+/// The body of a successful response.
+fn read_response(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, Error> {
+    let http_error = |reason: String| Error::Http {
+        url: url.to_string(),
+        reason,
+    };
+
+    let mut response = response.map_err(|e| http_error(e.to_string()))?;
+    let status = response.status();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| http_error(e.to_string()))?;
+
+    match status.is_success() {
+        true => Ok(body),
+        false => Err(http_error(format!("{status}: {}", body.trim()))),
+    }
+}
+
+/// Download a day's input into `dir`, unless it's already there.
+///
+/// Returns where the input is, and whether it was downloaded.
+pub fn fetch(client: &Client, dir: &Path, day: usize) -> Result<(PathBuf, bool), Error> {
+    let Some(name) = input::file_name(day, false) else {
+        return Err(Error::Usage(format!(
+            "day {day} isn't between 1 and {}",
+            days::LAST_DAY
+        )));
+    };
+
+    let path = dir.join(name);
+    if path.is_file() {
+        return Ok((path, false));
+    }
+
+    let input = client.input(day)?;
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| Error::Io { path, source }
+    };
+
+    // write somewhere else first, so an interrupted write isn't cached
+    let partial = path.with_extension("txt.part");
+    std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+    std::fs::write(&partial, input).map_err(io_error(&partial))?;
+    std::fs::rename(&partial, &path).map_err(io_error(&path))?;
+
+    Ok((path, true))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    /// Serve each of `responses` to one connection in turn, returning
+    /// the server's base URL and a handle to the requests it received.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (url, handle)
+    }
+
+    /// A fresh, empty directory for a test.
+    pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-2025-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetched_once() {
+        let (url, server) = serve(vec![(200, "1,2,3\n")]);
+        let client = Client::new(format!("{url}/"), "abc");
+        let dir = scratch_dir("fetch");
+
+        let (path, downloaded) = fetch(&client, &dir, 3).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, dir.join("three.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+        // the server only answers once, so this must be cached
+        let (cached, downloaded) = fetch(&client, &dir, 3).unwrap();
+        assert!(!downloaded);
+        assert_eq!(cached, path);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_failed() {
+        let (url, server) = serve(vec![(400, "Please log in")]);
+        let client = Client::new(url, "expired");
+        let dir = scratch_dir("fetch-failed");
+
+        let error = fetch(&client, &dir, 4).unwrap_err();
+        assert!(matches!(&error, Error::Http { reason, .. } if reason.contains("Please log in")));
+        assert!(!dir.join("four.txt").exists());
+        assert!(matches!(fetch(&client, &dir, 13), Err(Error::Usage(_))));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submitted() {
+        let (url, server) = serve(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let client = Client::new(url, "abc");
+
+        let reply = client.submit(1, Part::Two, &Answer::from(6616)).unwrap();
+        assert!(matches!(
+            reply,
+            Reply::Wrong {
+                hint: crate::submissions::Hint::TooLow,
+                wait: None
+            }
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=6616"));
+    }
+
+    #[test]
+    fn session_from_file() {
+        let dir = scratch_dir("session");
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("session");
+        std::fs::write(&file, "  token\n").unwrap();
+
+        // the environment takes priority, so only check files when it's unset
+        if std::env::var_os(SESSION_VAR).is_none() {
+            assert_eq!(session(&[dir.join("missing"), file]).unwrap(), "token");
+            assert!(matches!(
+                session(&[dir.join("missing")]),
+                Err(Error::MissingSession { searched }) if searched.len() == 1
+            ));
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,9 +1,21 @@
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
 use std::{
     cmp::Reverse,
     collections::{BinaryHeap, HashMap, HashSet},
-    fmt::Display,
 };
 
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(8, Part::One, "heap", |input| part_one(input, 1000)),
+    &Solver::new(8, Part::One, "first_try", |input| {
+        part_one_first_try(input, 1000)
+    }),
+    &Solver::new(8, Part::Two, "default", part_two),
+];
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 struct Coordinate {
     x: i64,
@@ -36,7 +48,7 @@ impl Ord for Pair {
 
 impl PartialOrd for Pair {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(other.distance.cmp(&self.distance))
+        Some(self.cmp(other))
     }
 }
 
@@ -46,8 +58,8 @@ impl PartialEq for Pair {
     }
 }
 
-pub fn part_one_first_try(input: &str, max_pairs: usize) -> impl Display {
-    let (_, pairs) = parse_junction_pairs(input);
+pub fn part_one_first_try(input: &str, max_pairs: usize) -> Result<Answer, Error> {
+    let (_, pairs) = parse_junction_pairs(input)?;
     let mut circuits = Vec::<HashSet<usize>>::new();
 
     for Pair { a, b, .. } in pairs.into_iter().take(max_pairs) {
@@ -93,11 +105,12 @@ pub fn part_one_first_try(input: &str, max_pairs: usize) -> impl Display {
     // max_three.into_iter().fold(1, |a, k| a * k)
 
     circuits.sort_unstable_by_key(|c| Reverse(c.len()));
-    circuits
+    Ok(circuits
         .iter()
         .take(3)
         .map(|c| c.len())
-        .fold(1, |a, k| a * k)
+        .product::<usize>()
+        .into())
 }
 
 #[derive(Default)]
@@ -153,8 +166,8 @@ impl Circuits {
     }
 }
 
-pub fn part_one(input: &str, max_pairs: usize) -> impl Display {
-    let mut pairs = parse_junction_pairs_heap(input);
+pub fn part_one(input: &str, max_pairs: usize) -> Result<Answer, Error> {
+    let mut pairs = parse_junction_pairs_heap(input)?;
     let mut circuits = Circuits::default();
 
     let mut count = 0;
@@ -189,36 +202,37 @@ pub fn part_one(input: &str, max_pairs: usize) -> impl Display {
         }
     }
 
-    max_three.into_iter().fold(1, |a, k| a * k)
+    Ok(max_three.into_iter().product::<usize>().into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
-    let (junctions, pairs) = parse_junction_pairs(input);
+pub fn part_two(input: &str) -> Result<Answer, Error> {
+    let (junctions, pairs) = parse_junction_pairs(input)?;
     let mut circuits = Circuits::default();
 
     let mut last_pair = None;
     for pair in pairs {
-        if circuits.insert_pair(pair.clone()) {
+        if circuits.insert_pair(pair) {
             last_pair = Some(pair);
         }
     }
 
-    let last_pair = last_pair.unwrap();
-    junctions[last_pair.a].x * junctions[last_pair.b].x
+    let last_pair =
+        last_pair.ok_or_else(|| Error::Failed("at least two junction boxes are needed".into()))?;
+    Ok((junctions[last_pair.a].x * junctions[last_pair.b].x).into())
 }
 
-fn parse_junction_pairs_heap(input: &str) -> BinaryHeap<Pair> {
-    let junctions: Vec<_> = input
-        .lines()
-        .flat_map(|line| {
-            let mut coordinates = line.split(',');
-            let x = coordinates.next()?.parse().ok()?;
-            let y = coordinates.next()?.parse().ok()?;
-            let z = coordinates.next()?.parse().ok()?;
-
-            Some(Coordinate { x, y, z })
+fn parse_junctions(input: &str) -> Result<Vec<Coordinate>, Error> {
+    parse::lines(input.as_bytes())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let [x, y, z] = line.tuple(b',')?;
+            Ok(Coordinate { x, y, z })
         })
-        .collect();
+        .collect()
+}
+
+fn parse_junction_pairs_heap(input: &str) -> Result<BinaryHeap<Pair>, Error> {
+    let junctions = parse_junctions(input)?;
 
     // Find all unique connections and sort them.
     let mut pairs = BinaryHeap::new();
@@ -233,21 +247,11 @@ fn parse_junction_pairs_heap(input: &str) -> BinaryHeap<Pair> {
         }
     }
 
-    pairs
+    Ok(pairs)
 }
 
-fn parse_junction_pairs(input: &str) -> (Vec<Coordinate>, Vec<Pair>) {
-    let junctions: Vec<_> = input
-        .lines()
-        .flat_map(|line| {
-            let mut coordinates = line.split(',');
-            let x = coordinates.next()?.parse().ok()?;
-            let y = coordinates.next()?.parse().ok()?;
-            let z = coordinates.next()?.parse().ok()?;
-
-            Some(Coordinate { x, y, z })
-        })
-        .collect();
+fn parse_junction_pairs(input: &str) -> Result<(Vec<Coordinate>, Vec<Pair>), Error> {
+    let junctions = parse_junctions(input)?;
 
     // Find all unique connections and sort them.
     let mut pairs = Vec::with_capacity(junctions.len().pow(2));
@@ -263,7 +267,7 @@ fn parse_junction_pairs(input: &str) -> (Vec<Coordinate>, Vec<Pair>) {
     }
     pairs.sort_unstable_by_key(|p| p.distance);
 
-    (junctions, pairs)
+    Ok((junctions, pairs))
 }
 
 #[cfg(test)]
@@ -273,12 +277,21 @@ mod test {
     #[test]
     fn eight() {
         let input = include_str!("../../inputs/eight_test.txt");
-        assert_eq!("40", part_one(input, 10).to_string().as_str());
+        assert_eq!(part_one(input, 10).unwrap(), 40);
     }
 
     #[test]
     fn eight2() {
         let input = include_str!("../../inputs/eight_test.txt");
-        assert_eq!("25272", part_two(input).to_string().as_str());
+        assert_eq!(part_two(input).unwrap(), 25272);
+    }
+
+    #[test]
+    fn eight_malformed() {
+        assert!(matches!(
+            part_two("1,2,3\n4,5"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(part_two("1,2,3"), Err(Error::Failed(_))));
     }
 }
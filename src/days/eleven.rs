@@ -1,25 +1,40 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Display,
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
 };
+use std::collections::{HashMap, HashSet};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(11, Part::One, "default", part_one),
+    &Solver::new(11, Part::Two, "default", part_two),
+    &Solver::new(11, Part::Two, "slow", part_two_slow),
+];
 
 type Graph<'a> = HashMap<&'a str, Vec<&'a str>>;
 type Explored<'a> = HashMap<&'a str, usize>;
 
-fn parse_graph(input: &str) -> HashMap<&str, Vec<&str>> {
+fn parse_graph(input: &str) -> Result<Graph<'_>, Error> {
     let mut graph = HashMap::new();
 
-    for line in input.lines() {
-        let key = &line[..3];
-        let connections = line[5..].split(' ').collect();
-        graph.insert(key, connections);
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let Some((key, connections)) = line
+            .split_once(": ")
+            .filter(|(key, connections)| !key.is_empty() && !connections.is_empty())
+        else {
+            return Err(Error::parse(
+                input.as_bytes(),
+                line.as_bytes(),
+                "a device like `aaa: you hhh`",
+            ));
+        };
+        graph.insert(key, connections.split(' ').collect());
     }
 
-    graph
+    Ok(graph)
 }
 
-pub fn part_one(input: &str) -> impl Display {
-    let graph = parse_graph(input);
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let graph = parse_graph(input)?;
 
     let mut explored_paths: Explored = HashMap::new();
 
@@ -33,10 +48,10 @@ pub fn part_one(input: &str) -> impl Display {
 
         for child in children.iter().flat_map(|c| c.iter()) {
             let child_paths = match explored.get(child) {
-                Some(child_paths) => child_paths.clone(),
+                Some(child_paths) => *child_paths,
                 None => {
                     let child_paths = explore_paths(graph, child, explored);
-                    explored.insert(child, child_paths.clone());
+                    explored.insert(child, child_paths);
                     child_paths
                 }
             };
@@ -47,15 +62,13 @@ pub fn part_one(input: &str) -> impl Display {
         counts
     }
 
-    let paths = explore_paths(&graph, "you", &mut explored_paths);
-
-    paths
+    Ok(explore_paths(&graph, "you", &mut explored_paths).into())
 }
 
 type ExploredSet<'a> = HashMap<&'a str, Vec<HashSet<&'a str>>>;
 
-pub fn part_two_slow(input: &str) -> impl Display {
-    let graph = parse_graph(input);
+pub fn part_two_slow(input: &str) -> Result<Answer, Error> {
+    let graph = parse_graph(input)?;
 
     let mut explored_paths: ExploredSet = HashMap::new();
 
@@ -92,13 +105,14 @@ pub fn part_two_slow(input: &str) -> impl Display {
 
     let paths = explore_paths(&graph, "svr", &mut explored_paths);
 
-    paths
+    Ok(paths
         .iter()
         .filter(|p| p.contains("fft") && p.contains("dac"))
         .count()
+        .into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     #[derive(Clone)]
     enum SearchState {
         Out(usize),
@@ -131,15 +145,15 @@ pub fn part_two(input: &str) -> impl Display {
             }
         }
 
-        fn both(&self) -> usize {
+        fn both(&self) -> Option<usize> {
             match self {
-                Self::Both(b) => *b,
-                _ => panic!("expected Both"),
+                Self::Both(b) => Some(*b),
+                _ => None,
             }
         }
     }
 
-    let graph = parse_graph(input);
+    let graph = parse_graph(input)?;
     let mut explored_paths: ExploredPair = HashMap::new();
 
     fn explore_paths<'a>(
@@ -188,7 +202,8 @@ pub fn part_two(input: &str) -> impl Display {
     }
 
     let paths = explore_paths(&graph, "svr", "out", &mut explored_paths);
-    paths.both()
+    // like the slow search, count nothing if no path visits both
+    Ok(paths.both().unwrap_or(0).into())
 }
 
 #[cfg(test)]
@@ -198,16 +213,27 @@ mod test {
     #[test]
     fn eleven() {
         let input = include_str!("../../inputs/eleven_test.txt");
-        assert_eq!("5", part_one(input).to_string().as_str());
+        assert_eq!(part_one(input).unwrap(), 5);
     }
 
     #[test]
     fn eleven2() {
         let input = include_str!("../../inputs/eleven_test2.txt");
-        assert_eq!("2", part_two(input).to_string().as_str());
+        assert_eq!(part_two(input).unwrap(), 2);
 
         let input = include_str!("../../inputs/eleven_test3.txt");
-        assert_eq!("6", part_two_slow(input).to_string().as_str());
-        assert_eq!("6", part_two(input).to_string().as_str());
+        assert_eq!(part_two_slow(input).unwrap(), 6);
+        assert_eq!(part_two(input).unwrap(), 6);
+    }
+
+    #[test]
+    fn eleven_malformed() {
+        assert!(matches!(
+            part_one("you: out\naaa out"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        let input = include_str!("../../inputs/eleven_test.txt");
+        assert_eq!(part_two(input).unwrap(), 0);
+        assert_eq!(part_two_slow(input).unwrap(), 0);
     }
 }
@@ -1,31 +1,45 @@
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
 use core::ops::RangeInclusive;
-use std::fmt::Display;
 
-pub fn part_one(input: &str) -> impl Display {
-    let input = input.as_bytes();
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(5, Part::One, "default", part_one),
+    &Solver::new(5, Part::Two, "default", part_two),
+];
 
-    let split = input
-        .windows(2)
-        .enumerate()
-        .find_map(|(i, window)| (window == b"\n\n").then_some(i))
-        .unwrap();
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let input = input.as_bytes();
 
-    let ranges = &input[..split];
-    let available_ids = &input[split + 2..];
+    let mut sections = parse::sections(input);
+    let ranges = sections.next().unwrap_or_default();
+    let available_ids = sections.next().ok_or_else(|| {
+        Error::parse(
+            input,
+            &input[input.len()..],
+            "a blank line between the ranges and IDs",
+        )
+    })?;
 
-    let ranges = parse_ranges(ranges);
+    let ranges = parse_ranges(input, ranges)?;
 
     // NOTE: Collecting the values before counting
     // is actually about 5% faster.
-    available_ids
-        .split(|c| *c == b'\n')
-        .map(parse_ascii_number)
-        .filter(|id| ranges.iter().any(|(l, h)| (*l..=*h).contains(&id)))
-        .count()
+    let mut fresh = 0;
+    for id in parse::lines_in(input, available_ids).filter(|id| !id.is_empty()) {
+        let id: u64 = id.integer()?;
+        if ranges.iter().any(|(l, h)| (*l..=*h).contains(&id)) {
+            fresh += 1;
+        }
+    }
+
+    Ok(fresh.into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
-    let mut ranges = parse_ranges(input.as_bytes());
+pub fn part_two(input: &str) -> Result<Answer, Error> {
+    let mut ranges = parse_ranges(input.as_bytes(), input.as_bytes())?;
 
     let mut unique_fresh = 0;
     let mut observed: Vec<RangeInclusive<_>> = Vec::new();
@@ -52,10 +66,10 @@ pub fn part_two(input: &str) -> impl Display {
 
                     range = *range.start()..=(observed.start() - 1);
                     has_changed = true;
-                } else if observed.contains(&range.start()) {
+                } else if observed.contains(range.start()) {
                     range = (observed.end() + 1)..=*range.end();
                     has_changed = true;
-                } else if observed.contains(&range.end()) {
+                } else if observed.contains(range.end()) {
                     range = *range.start()..=(observed.start() - 1);
                     has_changed = true;
                 }
@@ -71,7 +85,7 @@ pub fn part_two(input: &str) -> impl Display {
         i += 1;
     }
 
-    unique_fresh
+    Ok(unique_fresh.into())
 }
 
 type Range = RangeInclusive<u64>;
@@ -86,26 +100,11 @@ impl SubsetOf for Range {
     }
 }
 
-fn parse_ascii_number(number: &[u8]) -> u64 {
-    let mut value = 0;
-    for byte in number {
-        value *= 10;
-        value += (byte - b'0') as u64;
-    }
-    value
-}
-
-fn parse_ranges(ranges: &[u8]) -> Vec<(u64, u64)> {
-    ranges
-        .split(|c| *c == b'\n')
-        .map_while(|range| {
-            let mut pair = range.split(|c| *c == b'-');
-
-            let left = parse_ascii_number(pair.next()?);
-            let right = parse_ascii_number(pair.next()?);
-
-            Some((left, right))
-        })
+/// Parse ranges like `3-5`, one per line, up to the first blank line.
+fn parse_ranges(input: &[u8], ranges: &[u8]) -> Result<Vec<(u64, u64)>, Error> {
+    parse::lines_in(input, ranges)
+        .take_while(|range| !range.is_empty())
+        .map(|range| range.range())
         .collect()
 }
 
@@ -116,18 +115,35 @@ mod test {
     #[test]
     fn test_five() {
         let test = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
-        assert_eq!("3", part_one(test).to_string().as_str());
+        assert_eq!(part_one(test).unwrap(), 3);
     }
 
     #[test]
     fn test_five_two() {
         let test = "3-5\n10-14\n16-20\n12-18\n\n";
-        assert_eq!("14", part_two(test).to_string().as_str());
+        assert_eq!(part_two(test).unwrap(), 14);
     }
 
     #[test]
     fn test_five_2() {
         let test = "5-10\n4-11\n6-9\n\n";
-        assert_eq!("8", part_two(test).to_string().as_str());
+        assert_eq!(part_two(test).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_five_malformed() {
+        assert!(part_one("3-5\n10-14").is_err());
+        assert!(matches!(
+            part_one("3-5\n10_14\n\n1"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            part_one("3-5\n\n1\n2a"),
+            Err(Error::Parse {
+                line: 4,
+                column: 2,
+                ..
+            })
+        ));
     }
 }
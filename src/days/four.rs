@@ -1,49 +1,44 @@
-use std::fmt::Display;
-
-struct Grid {
-    data: Vec<u8>,
-    width: usize,
-    height: usize,
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    grid::Grid,
+};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(4, Part::One, "default", part_one),
+    &Solver::new(4, Part::Two, "indices", part_two),
+    &Solver::new(4, Part::Two, "simple", part_two_simple),
+];
+
+/// The floor of the printing department, `@` where there's a roll
+/// of paper.
+struct Rolls {
+    grid: Grid<u8>,
 }
 
-impl Grid {
-    pub fn new(data: &[u8]) -> Self {
-        let width = data.iter().position(|p| *p == b'\n').unwrap();
-        let data: Vec<_> = data.iter().copied().filter(|c| *c != b'\n').collect();
-
-        Self {
-            width,
-            height: data.len() / width,
-            data,
-        }
-    }
-
-    fn get(&self, x: i16, y: i16) -> Option<u8> {
-        Some(self.data[index(self.width, self.height, x, y)?])
+impl Rolls {
+    pub fn new(input: &[u8]) -> Result<Self, Error> {
+        let grid = Grid::parse(
+            input,
+            |c| matches!(c, b'.' | b'@').then_some(c),
+            "`.` or `@`",
+        )?;
+        Ok(Self { grid })
     }
 
-    pub fn paper_around(&self, x: i16, y: i16) -> u32 {
-        let mut total = 0;
-        for j in (y - 1)..=(y + 1) {
-            for i in (x - 1)..=(x + 1) {
-                if i == x && j == y {
-                    continue;
-                }
-
-                if let Some(b'@') = self.get(i, j) {
-                    total += 1;
-                }
-            }
-        }
-        total
+    pub fn paper_around(&self, x: usize, y: usize) -> u32 {
+        self.grid
+            .neighbors8(x, y)
+            .filter(|position| self.grid[*position] == b'@')
+            .count() as u32
     }
 
     pub fn neighbor_list(&self) -> Vec<u32> {
         let mut neighbors = Vec::new();
-        neighbors.reserve_exact(self.data.len());
-        for y in 0..self.height as i16 {
-            for x in 0..self.width as i16 {
-                if self.get(x, y) == Some(b'@') {
+        neighbors.reserve_exact(self.grid.cells().len());
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                if self.grid[(x, y)] == b'@' {
                     let paper = self.paper_around(x, y);
                     neighbors.push(paper);
                 } else {
@@ -55,7 +50,8 @@ impl Grid {
     }
 
     pub fn indices(&self) -> Vec<usize> {
-        self.data
+        self.grid
+            .cells()
             .iter()
             .enumerate()
             .filter_map(|(i, c)| if *c == b'@' { Some(i) } else { None })
@@ -75,23 +71,7 @@ impl Grid {
 
             if neighbor_list[i] < 4 {
                 total_removed += 1;
-
-                let x = (i % self.width) as i16;
-                let y = (i / self.width) as i16;
-
-                // clean up neighbors
-                for j in (y - 1)..=(y + 1) {
-                    for i in (x - 1)..=(x + 1) {
-                        if i == x && j == y {
-                            continue;
-                        }
-
-                        if let Some(index) = index(self.width, self.height, i, j) {
-                            neighbor_list[index] -= 1;
-                        }
-                    }
-                }
-
+                self.clean_up_neighbors(i, neighbor_list);
                 indices.swap_remove(i2);
             }
 
@@ -103,83 +83,68 @@ impl Grid {
 
     pub fn remove_accessible(&mut self, neighbor_list: &mut [u32]) -> usize {
         let mut total_removed = 0;
-        for (i, char) in self.data.iter_mut().enumerate() {
-            if *char == b'@' && neighbor_list[i] < 4 {
-                *char = b'x';
+        for i in 0..self.grid.cells().len() {
+            if self.grid.cells()[i] == b'@' && neighbor_list[i] < 4 {
+                self.grid.cells_mut()[i] = b'x';
                 total_removed += 1;
-
-                let x = (i % self.width) as i16;
-                let y = (i / self.width) as i16;
-
-                // clean up neighbors
-                for j in (y - 1)..=(y + 1) {
-                    for i in (x - 1)..=(x + 1) {
-                        if i == x && j == y {
-                            continue;
-                        }
-
-                        if let Some(index) = index(self.width, self.height, i, j) {
-                            neighbor_list[index] -= 1;
-                        }
-                    }
-                }
+                self.clean_up_neighbors(i, neighbor_list);
             }
         }
         total_removed
     }
-}
 
-fn index(width: usize, height: usize, x: i16, y: i16) -> Option<usize> {
-    if !(0..width as i16).contains(&x) {
-        return None;
-    }
-    if !(0..height as i16).contains(&y) {
-        return None;
+    fn clean_up_neighbors(&self, i: usize, neighbor_list: &mut [u32]) {
+        let (x, y) = self.grid.position(i);
+        for (x, y) in self.grid.neighbors8(x, y) {
+            // cells without paper are counted as zero
+            if let Some(index) = self.grid.index(x, y) {
+                neighbor_list[index] = neighbor_list[index].saturating_sub(1);
+            }
+        }
     }
-
-    Some(x as usize + y as usize * width)
 }
 
-pub fn part_one(input: &str) -> impl Display {
-    Grid::new(input.as_bytes())
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    Ok(Rolls::new(input.as_bytes())?
         .neighbor_list()
         .into_iter()
         .filter(|n| *n < 4)
         .count()
+        .into())
 }
 
 // 10132
-pub fn part_two_simple(input: &str) -> impl Display {
-    let mut grid = Grid::new(input.as_bytes());
+pub fn part_two_simple(input: &str) -> Result<Answer, Error> {
+    let mut rolls = Rolls::new(input.as_bytes())?;
 
     let mut total_removed = 0;
-    let mut neighbor_list = grid.neighbor_list();
+    let mut neighbor_list = rolls.neighbor_list();
     loop {
-        let removed = grid.remove_accessible(&mut neighbor_list);
+        let removed = rolls.remove_accessible(&mut neighbor_list);
         total_removed += removed;
         if removed == 0 {
             break;
         }
     }
 
-    total_removed
+    Ok(total_removed.into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
-    let mut grid = Grid::new(input.as_bytes());
+pub fn part_two(input: &str) -> Result<Answer, Error> {
+    let mut rolls = Rolls::new(input.as_bytes())?;
 
     let mut total_removed = 0;
-    let mut neighbor_list = grid.neighbor_list();
-    let mut indices = grid.indices();
+    let mut neighbor_list = rolls.neighbor_list();
+    let mut indices = rolls.indices();
     loop {
-        let removed = grid.remove_accessible_with_indices(&mut neighbor_list, &mut indices);
+        let removed = rolls.remove_accessible_with_indices(&mut neighbor_list, &mut indices);
         total_removed += removed;
         if removed == 0 {
             break;
         }
     }
 
-    total_removed
+    Ok(total_removed.into())
 }
 
 #[cfg(test)]
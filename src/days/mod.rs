@@ -9,3 +9,183 @@ pub mod six;
 pub mod ten;
 pub mod three;
 pub mod two;
+
+use crate::{Answer, Error};
+use std::{fmt::Display, sync::LazyLock};
+
+/// The number of days in this year's event.
+pub const LAST_DAY: usize = 12;
+
+const NAMES: [&str; LAST_DAY] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+    "twelve",
+];
+
+/// The spelled-out name of a day, as used for module and input file names.
+pub fn name(day: usize) -> Option<&'static str> {
+    NAMES.get(day.checked_sub(1)?).copied()
+}
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> usize {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single implementation of one part of a day.
+///
+/// Days may register several variants for the same part
+/// (like a simple and a threaded approach).
+pub trait Solution: Sync {
+    fn day(&self) -> usize;
+
+    fn part(&self) -> Part;
+
+    /// The name distinguishing this implementation from other variants.
+    fn variant(&self) -> &'static str;
+
+    fn solve(&self, input: &str) -> Result<Answer, Error>;
+}
+
+/// A [`Solution`] backed by a plain function.
+pub struct Solver {
+    day: usize,
+    part: Part,
+    variant: &'static str,
+    solve: fn(&str) -> Result<Answer, Error>,
+}
+
+impl Solver {
+    pub const fn new(
+        day: usize,
+        part: Part,
+        variant: &'static str,
+        solve: fn(&str) -> Result<Answer, Error>,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            variant,
+            solve,
+        }
+    }
+}
+
+impl Solution for Solver {
+    fn day(&self) -> usize {
+        self.day
+    }
+
+    fn part(&self) -> Part {
+        self.part
+    }
+
+    fn variant(&self) -> &'static str {
+        self.variant
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Error> {
+        (self.solve)(input)
+    }
+}
+
+/// Every module's solvers. Within a module, the first
+/// solver registered for a part is its preferred variant.
+const MODULES: &[&[&dyn Solution]] = &[
+    one::SOLVERS,
+    two::SOLVERS,
+    three::SOLVERS,
+    four::SOLVERS,
+    five::SOLVERS,
+    six::second_try::SOLVERS,
+    six::first_try::SOLVERS,
+    seven::second_try::SOLVERS,
+    seven::first_try::SOLVERS,
+    eight::SOLVERS,
+    nine::SOLVERS,
+    ten::SOLVERS,
+    eleven::SOLVERS,
+];
+
+static REGISTRY: LazyLock<Vec<&'static dyn Solution>> = LazyLock::new(|| {
+    let mut solutions: Vec<_> = MODULES.iter().flat_map(|m| m.iter().copied()).collect();
+    // stable, so variant preference is kept
+    solutions.sort_by_key(|s| (s.day(), s.part()));
+    solutions
+});
+
+/// Every registered solver, ordered by day and part.
+pub fn registry() -> &'static [&'static dyn Solution] {
+    &REGISTRY
+}
+
+/// All variants for a day's part, starting with the preferred one.
+pub fn variants(day: usize, part: Part) -> impl Iterator<Item = &'static dyn Solution> {
+    registry()
+        .iter()
+        .copied()
+        .filter(move |s| s.day() == day && s.part() == part)
+}
+
+/// Find a solver, falling back to the preferred variant if none is named.
+pub fn find(day: usize, part: Part, variant: Option<&str>) -> Result<&'static dyn Solution, Error> {
+    let mut candidates = variants(day, part);
+    let found = match variant {
+        Some(name) => candidates.find(|s| s.variant() == name),
+        None => candidates.next(),
+    };
+
+    found.ok_or_else(|| Error::Unsolved {
+        day,
+        part,
+        variant: variant.map(str::to_string),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_day_registered() {
+        for day in 1..=11 {
+            for part in [Part::One, Part::Two] {
+                assert!(find(day, part, None).is_ok(), "day {day} part {part}");
+            }
+        }
+
+        assert!(find(0, Part::One, None).is_err());
+        assert!(find(1, Part::One, Some("nonexistent")).is_err());
+    }
+
+    #[test]
+    fn unique_variants() {
+        for (i, a) in registry().iter().enumerate() {
+            for b in &registry()[i + 1..] {
+                assert!(
+                    (a.day(), a.part(), a.variant()) != (b.day(), b.part(), b.variant()),
+                    "duplicate variant `{}` for day {} part {}",
+                    a.variant(),
+                    a.day(),
+                    a.part(),
+                );
+            }
+        }
+    }
+}
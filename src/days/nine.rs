@@ -1,8 +1,15 @@
-use std::{
-    cmp::Reverse,
-    fmt::Display,
-    sync::{Arc, Mutex, atomic::AtomicUsize},
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    grid::Grid,
+    parse,
 };
+use std::cmp::Reverse;
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(9, Part::One, "default", part_one),
+    &Solver::new(9, Part::Two, "default", part_two),
+];
 
 #[derive(Clone, Copy)]
 struct Point {
@@ -20,77 +27,18 @@ fn rect_from(a: Point, b: Point) -> (Point, Point) {
     (Point { x: tl_x, y: tl_y }, Point { x: br_x, y: br_y })
 }
 
-struct Frame {
-    data: Vec<u8>,
-    width: usize,
-    height: usize,
-}
-
-impl Frame {
-    pub fn new(width: usize, height: usize) -> Self {
-        Self {
-            width,
-            height,
-            data: vec![b'.'; width * height],
-        }
-    }
-
-    fn get(&self, x: i64, y: i64) -> u8 {
-        self.data[index(self.width, self.height, x, y).unwrap()]
-    }
-
-    fn get_mut(&mut self, x: i64, y: i64) -> &mut u8 {
-        // let index = index(self.width, self.height, x, y);
-        // if index.is_none() {
-        //     println!(
-        //         "x: {x}, y: {y}, width: {}, height: {}",
-        //         self.width, self.height
-        //     );
-        // }
-        &mut self.data[index(self.width, self.height, x, y).unwrap()]
-    }
-}
-
-impl core::fmt::Display for Frame {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            let start = y * self.width;
-            let end = start + self.width;
-
-            write!(
-                f,
-                "{}\n",
-                std::str::from_utf8(&self.data[start..end]).unwrap()
-            )?;
-        }
-
-        Ok(())
-    }
-}
-
-fn index(width: usize, height: usize, x: i64, y: i64) -> Option<usize> {
-    if !(0..width as i64).contains(&x) {
-        return None;
-    }
-    if !(0..height as i64).contains(&y) {
-        return None;
-    }
-
-    Some(x as usize + y as usize * width)
+fn parse_corners(input: &str) -> Result<Vec<Point>, Error> {
+    parse::lines(input.as_bytes())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let [x, y] = line.tuple(b',')?;
+            Ok(Point { x, y })
+        })
+        .collect()
 }
 
-pub fn part_one(input: &str) -> impl Display {
-    let corners = input
-        .lines()
-        .flat_map(|line| {
-            let mut bits = line.split(',');
-
-            Some(Point {
-                x: bits.next()?.parse().ok()?,
-                y: bits.next()?.parse().ok()?,
-            })
-        })
-        .collect::<Vec<_>>();
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let corners = parse_corners(input)?;
 
     let mut largest = 0;
     for (i, a) in corners.iter().enumerate() {
@@ -108,21 +56,11 @@ pub fn part_one(input: &str) -> impl Display {
         }
     }
 
-    largest
+    Ok(largest.into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
-    let mut corners = input
-        .lines()
-        .flat_map(|line| {
-            let mut bits = line.split(',');
-
-            Some(Point {
-                x: bits.next()?.parse().ok()?,
-                y: bits.next()?.parse().ok()?,
-            })
-        })
-        .collect::<Vec<_>>();
+pub fn part_two(input: &str) -> Result<Answer, Error> {
+    let mut corners = parse_corners(input)?;
 
     // process into minimum buffer
     let mut field_br = Point { x: 0, y: 0 };
@@ -150,7 +88,7 @@ pub fn part_two(input: &str) -> impl Display {
     let width = (field_br.x - field_tl.x).max(0) as usize + 1;
     let height = (field_br.y - field_tl.y).max(0) as usize + 1;
 
-    let mut frame = Frame::new(width, height);
+    let mut frame = Grid::new(width, height, b'.');
 
     // massage coordinates into buffer's space
     for point in &mut corners {
@@ -159,7 +97,10 @@ pub fn part_two(input: &str) -> impl Display {
     }
 
     // create lines
-    let final_pair = [corners.last().copied().unwrap(), corners[0]];
+    let (Some(first), Some(last)) = (corners.first(), corners.last()) else {
+        return Err(Error::Failed("there are no red tiles".into()));
+    };
+    let final_pair = [*last, *first];
     for pair in corners
         .windows(2)
         .chain(std::iter::once(final_pair.as_slice()))
@@ -172,18 +113,18 @@ pub fn part_two(input: &str) -> impl Display {
             let start = b.y.min(a.y);
             let end = b.y.max(a.y);
             for y in start..=end {
-                *frame.get_mut(a.x, y) = b'X';
+                frame[(a.x as usize, y as usize)] = b'X';
             }
         } else {
             let start = b.x.min(a.x);
             let end = b.x.max(a.x);
             for x in start..=end {
-                *frame.get_mut(x, a.y) = b'X';
+                frame[(x as usize, a.y as usize)] = b'X';
             }
         }
     }
 
-    println!("created lines");
+    log::debug!("created lines");
 
     // fill
     for (i, pair) in corners
@@ -195,25 +136,25 @@ pub fn part_two(input: &str) -> impl Display {
         let b = pair[1];
 
         // fill pct
-        println!(
+        log::info!(
             "rasterizing {:.2}%",
             (i as f64 / corners.len() as f64) * 100.0
         );
 
         if a.x < b.x {
             for x in a.x..=b.x {
-                for y in a.y + 1..(frame.height as i64 - 1) {
-                    if frame.get(x, y) == b'X' {
+                for y in a.y + 1..(frame.height() as i64 - 1) {
+                    if frame[(x as usize, y as usize)] == b'X' {
                         break;
                     } else {
-                        *frame.get_mut(x, y) = b'X';
+                        frame[(x as usize, y as usize)] = b'X';
                     }
                 }
             }
         }
     }
 
-    println!("finished rasterizing");
+    log::debug!("finished rasterizing");
 
     // test
     let mut pairs = Vec::new();
@@ -235,7 +176,7 @@ pub fn part_two(input: &str) -> impl Display {
     let total = pairs.len() as f64;
     'outer: for (index, (area, i, j)) in pairs.into_iter().enumerate() {
         if (index % 1000) == 0 {
-            println!("tested {:.2}%", (index as f64 / total) * 100.0);
+            log::info!("tested {:.2}%", (index as f64 / total) * 100.0);
         }
 
         let a = corners[i];
@@ -245,29 +186,29 @@ pub fn part_two(input: &str) -> impl Display {
 
         // we only need to check the bounding line segments
         for x in tl.x..=br.x {
-            if frame.get(x, tl.y) != b'X' {
+            if frame[(x as usize, tl.y as usize)] != b'X' {
                 continue 'outer;
             }
 
-            if frame.get(x, br.y) != b'X' {
+            if frame[(x as usize, br.y as usize)] != b'X' {
                 continue 'outer;
             }
         }
 
         for y in tl.y..=br.y {
-            if frame.get(tl.x, y) != b'X' {
+            if frame[(tl.x as usize, y as usize)] != b'X' {
                 continue 'outer;
             }
 
-            if frame.get(br.x, y) != b'X' {
+            if frame[(br.x as usize, y as usize)] != b'X' {
                 continue 'outer;
             }
         }
 
-        return area;
+        return Ok(area.into());
     }
 
-    0
+    Ok(0.into())
 }
 
 #[cfg(test)]
@@ -278,13 +219,13 @@ mod test {
     fn nine() {
         let input = include_str!("../../inputs/nine_test.txt");
 
-        assert_eq!("50", part_one(input).to_string().as_str());
+        assert_eq!(part_one(input).unwrap(), 50);
     }
 
     #[test]
     fn nine2() {
         let input = include_str!("../../inputs/nine_test.txt");
 
-        assert_eq!("24", part_two(input).to_string().as_str());
+        assert_eq!(part_two(input).unwrap(), 24);
     }
 }
@@ -1,90 +1,460 @@
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
 use std::fmt::Display;
 
-enum Rotation {
-    Left(u32),
-    Right(u32),
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(1, Part::One, "default", part_one),
+    &Solver::new(1, Part::Two, "default", part_two),
+];
+
+/// One turn of the dial, from a line like `L68`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Left(u64),
+    Right(u64),
 }
 
 impl Rotation {
-    fn iter_sides(input: &str) -> impl Iterator<Item = Rotation> {
-        input.lines().flat_map(|l| match l.bytes().next()? {
-            b'L' => Some(Self::Left(l[1..].parse().ok()?)),
-            b'R' => Some(Self::Right(l[1..].parse().ok()?)),
-            _ => None,
-        })
+    fn parse(line: &parse::Line) -> Result<Self, Error> {
+        let Some((direction, amount)) = line.bytes.split_first() else {
+            return Err(line.error(line.bytes, "a rotation like `L68`"));
+        };
+
+        let amount = parse::integer(line.source(), amount)?;
+        match direction {
+            b'L' => Ok(Self::Left(amount)),
+            b'R' => Ok(Self::Right(amount)),
+            _ => Err(line.error(&line.bytes[..1], "`L` or `R`")),
+        }
     }
 }
 
-struct Lock {
-    tick: i32,
-    zeroes: u32,
+/// How [`rotations`] treats input that isn't only rotations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parsing {
+    /// Every line is a rotation, ending in `\n`.
+    #[default]
+    Strict,
+    /// Blank lines are skipped and lines can end in `\r\n`.
+    Lenient,
 }
 
-impl Default for Lock {
+/// The rotations in `input`, with the lines they're on.
+///
+/// Anything that isn't a rotation is an error giving its line,
+/// except for what [`Parsing::Lenient`] lets through.
+pub fn rotations(input: &str, parsing: Parsing) -> Result<Vec<(usize, Rotation)>, Error> {
+    let source = input.as_bytes();
+    if parsing == Parsing::Strict
+        && let Some(i) = source.iter().position(|b| *b == b'\r')
+    {
+        let start = source[..i]
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |n| n + 1);
+        return Err(Error::parse(
+            source,
+            &source[start..i],
+            "a line ending in `\\n` rather than `\\r\\n`",
+        ));
+    }
+
+    parse::lines(source)
+        .filter(|line| parsing == Parsing::Strict || !line.bytes.trim_ascii().is_empty())
+        .map(|line| Ok((line.number, Rotation::parse(&line)?)))
+        .collect()
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Left(amount) => write!(f, "L{amount}"),
+            Self::Right(amount) => write!(f, "R{amount}"),
+        }
+    }
+}
+
+/// A dial numbered from 0 up to `size - 1`, like the one on the
+/// safe, which counts how often it points at any of its targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    /// Sorted, without repeats.
+    targets: Vec<u64>,
+}
+
+/// The safe's dial: 100 positions, starting at 50, and looking for 0.
+impl Default for Dial {
     fn default() -> Self {
         Self {
-            tick: 50,
-            zeroes: 0,
+            size: 100,
+            position: 50,
+            targets: vec![0],
         }
     }
 }
 
-impl Lock {
-    fn right(&mut self, value: u32) {
-        self.tick = (self.tick + value as i32) % 100;
-        if self.tick == 0 {
-            self.zeroes += 1;
+impl Dial {
+    pub fn new(
+        size: u64,
+        start: u64,
+        targets: impl IntoIterator<Item = u64>,
+    ) -> Result<Self, Error> {
+        if size == 0 {
+            return Err(Error::Usage("a dial needs at least one position".into()));
         }
-    }
 
-    fn left(&mut self, value: u32) {
-        self.tick = (self.tick - value as i32).rem_euclid(100);
-        if self.tick == 0 {
-            self.zeroes += 1;
+        let mut targets: Vec<_> = targets.into_iter().collect();
+        targets.sort_unstable();
+        targets.dedup();
+        if let Some(outside) = std::iter::once(start)
+            .chain(targets.iter().copied())
+            .find(|position| *position >= size)
+        {
+            return Err(Error::Usage(format!(
+                "position {outside} isn't on a dial numbered 0 to {}",
+                size - 1
+            )));
         }
+
+        Ok(Self {
+            size,
+            position: start,
+            targets,
+        })
     }
-}
 
-pub fn part_one(input: &str) -> impl Display {
-    let mut lock = Lock::default();
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
 
-    for rotation in Rotation::iter_sides(input) {
+    pub fn targets(&self) -> &[u64] {
+        &self.targets
+    }
+
+    pub fn on_target(&self) -> bool {
+        self.targets.binary_search(&self.position).is_ok()
+    }
+
+    /// Turn towards higher numbers, returning how many times the dial
+    /// points at a target on the way, counting where it stops.
+    pub fn right(&mut self, amount: u64) -> u128 {
+        self.turn(amount, true)
+    }
+
+    /// Like [`Dial::right`], but towards lower numbers.
+    pub fn left(&mut self, amount: u64) -> u128 {
+        self.turn(amount, false)
+    }
+
+    fn turn(&mut self, amount: u64, right: bool) -> u128 {
+        // wide enough that nothing here overflows
+        let (size, position, amount) = (self.size as u128, self.position as u128, amount as u128);
+
+        let passes = self
+            .targets
+            .iter()
+            .map(|target| {
+                let target = *target as u128;
+                // how far to turn before first pointing at it, which is
+                // a whole turn when already there
+                let first = match right {
+                    true => (target + size - position) % size,
+                    false => (position + size - target) % size,
+                };
+                let first = if first == 0 { size } else { first };
+
+                amount.checked_sub(first).map_or(0, |rest| rest / size + 1)
+            })
+            .sum();
+
+        let step = amount % size;
+        self.position = match right {
+            true => (position + step) % size,
+            false => (position + size - step) % size,
+        } as u64;
+
+        passes
+    }
+
+    /// Turn either way, returning what [`Dial::right`] or
+    /// [`Dial::left`] would.
+    pub fn rotate(&mut self, rotation: Rotation) -> u128 {
         match rotation {
-            Rotation::Left(amount) => lock.left(amount),
-            Rotation::Right(amount) => lock.right(amount),
+            Rotation::Left(amount) => self.left(amount),
+            Rotation::Right(amount) => self.right(amount),
+        }
+    }
+
+    /// Turn one click at a time, checking for a target after each.
+    ///
+    /// This gives the same count as [`Dial::rotate`], only far more
+    /// slowly, but it's simple enough to check that against.
+    pub fn click(&mut self, rotation: Rotation) -> u128 {
+        let (amount, step) = match rotation {
+            Rotation::Left(amount) => (amount, self.size - 1),
+            Rotation::Right(amount) => (amount, 1),
+        };
+
+        let mut passes = 0;
+        for _ in 0..amount {
+            self.position = ((self.position as u128 + step as u128) % self.size as u128) as u64;
+            passes += self.on_target() as u128;
         }
+        passes
     }
+}
+
+/// What one rotation did to the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// The line the rotation is on.
+    pub line: usize,
+    pub rotation: Rotation,
+    pub start: u64,
+    pub end: u64,
+    /// Whether it stopped on a target.
+    pub landed: bool,
+    /// How many times it pointed at a target, counting where it stopped.
+    pub passed: u128,
+}
+
+/// Follow the `rotations`, recording each step.
+pub fn trace(dial: Dial, rotations: &[(usize, Rotation)]) -> Vec<Step> {
+    steps(dial, rotations, Dial::rotate)
+}
 
-    lock.zeroes
+/// Like [`trace`], but turning the dial with [`Dial::click`].
+pub fn replay(dial: Dial, rotations: &[(usize, Rotation)]) -> Vec<Step> {
+    steps(dial, rotations, Dial::click)
+}
+
+fn steps(
+    mut dial: Dial,
+    rotations: &[(usize, Rotation)],
+    turn: fn(&mut Dial, Rotation) -> u128,
+) -> Vec<Step> {
+    rotations
+        .iter()
+        .map(|&(line, rotation)| {
+            let start = dial.position();
+            let passed = turn(&mut dial, rotation);
+            Step {
+                line,
+                rotation,
+                start,
+                end: dial.position(),
+                landed: dial.on_target(),
+                passed,
+            }
+        })
+        .collect()
 }
 
-impl Lock {
-    fn right_count_travel(&mut self, value: u32) {
-        let crossings = (self.tick as u32 + value) / 100;
-        self.zeroes += crossings;
+const TRACE_HEADER: [&str; 6] = ["Line", "Rotation", "Start", "End", "Landed", "Passed"];
 
-        self.tick = (self.tick + value as i32) % 100;
+fn trace_row(step: &Step) -> [String; 6] {
+    [
+        step.line.to_string(),
+        step.rotation.to_string(),
+        step.start.to_string(),
+        step.end.to_string(),
+        (step.landed as u8).to_string(),
+        step.passed.to_string(),
+    ]
+}
+
+/// Shows steps as a table, with the numbers lined up on the right.
+pub struct Table<'a>(pub &'a [Step]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<_> = self.0.iter().map(trace_row).collect();
+
+        let mut widths = TRACE_HEADER.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let header = TRACE_HEADER.map(String::from);
+        for row in std::iter::once(&header).chain(&rows) {
+            let cells: Vec<_> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect();
+            writeln!(f, "{}", cells.join("  "))?;
+        }
+
+        Ok(())
     }
+}
+
+/// Shows steps as CSV, with a header row.
+pub struct Csv<'a>(pub &'a [Step]);
 
-    fn left_count_travel(&mut self, value: u32) {
-        // The extra mod 100 removes miscounts when starting at zero.
-        let start = (100 - self.tick as u32) % 100;
-        let crossings = (start + value) / 100;
-        self.zeroes += crossings;
+impl Display for Csv<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", TRACE_HEADER.join(",").to_lowercase())?;
+        for step in self.0 {
+            writeln!(f, "{}", trace_row(step).join(","))?;
+        }
 
-        self.tick = (self.tick - value as i32).rem_euclid(100);
+        Ok(())
     }
 }
 
-pub fn part_two(input: &str) -> impl Display {
-    let mut lock = Lock::default();
+/// Count the `rotations` that leave `dial` pointing at one of
+/// its targets.
+pub fn landings(mut dial: Dial, rotations: &[(usize, Rotation)]) -> u128 {
+    rotations
+        .iter()
+        .map(|&(_, rotation)| {
+            dial.rotate(rotation);
+            dial.on_target() as u128
+        })
+        .sum()
+}
 
-    for rotation in Rotation::iter_sides(input) {
-        match rotation {
-            Rotation::Left(amount) => lock.left_count_travel(amount),
-            Rotation::Right(amount) => lock.right_count_travel(amount),
+/// Count every time `dial` points at one of its targets while
+/// following the `rotations`, not just where they stop.
+pub fn passes(mut dial: Dial, rotations: &[(usize, Rotation)]) -> u128 {
+    rotations
+        .iter()
+        .map(|&(_, rotation)| dial.rotate(rotation))
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let rotations = rotations(input, Parsing::Lenient)?;
+    Ok(landings(Dial::default(), &rotations).into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer, Error> {
+    let rotations = rotations(input, Parsing::Lenient)?;
+    Ok(passes(Dial::default(), &rotations).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    #[test]
+    fn example() {
+        assert_eq!(part_one(EXAMPLE).unwrap(), 3);
+        assert_eq!(part_two(EXAMPLE).unwrap(), 6);
+    }
+
+    #[test]
+    fn configured() {
+        let mut dial = Dial::new(10, 0, [3, 7, 3]).unwrap();
+        assert_eq!(dial.targets(), [3, 7]);
+        assert_eq!(dial.right(7), 2);
+        assert!(dial.on_target());
+        assert_eq!(dial.left(10), 2);
+        assert_eq!(dial.position(), 7);
+
+        let single = Dial::new(1, 0, [0]).unwrap();
+        let rotations = [(1, Rotation::Right(5)), (2, Rotation::Left(3))];
+        assert_eq!(landings(single.clone(), &rotations), 2);
+        assert_eq!(passes(single, &rotations), 8);
+
+        let mut dial = Dial::default();
+        assert_eq!(dial.right(u64::MAX), (u64::MAX as u128 + 50) / 100);
+        assert_eq!(dial.position(), 65);
+
+        assert!(Dial::new(0, 0, [0]).is_err());
+        assert!(Dial::new(100, 100, [0]).is_err());
+        assert!(Dial::new(100, 50, [100]).is_err());
+    }
+
+    #[test]
+    fn traced() {
+        let rotations = rotations(EXAMPLE, Parsing::Strict).unwrap();
+        let steps = trace(Dial::default(), &rotations);
+        assert_eq!(steps.len(), 10);
+        assert_eq!(
+            steps[0],
+            Step {
+                line: 1,
+                rotation: Rotation::Left(68),
+                start: 50,
+                end: 82,
+                landed: false,
+                passed: 1,
+            }
+        );
+        assert_eq!(steps.iter().map(|s| s.passed).sum::<u128>(), 6);
+
+        let csv = Csv(&steps[..2]).to_string();
+        assert_eq!(
+            csv,
+            "line,rotation,start,end,landed,passed\n1,L68,50,82,0,1\n2,L30,82,52,0,0\n"
+        );
+        assert!(
+            Table(&steps)
+                .to_string()
+                .starts_with("Line  Rotation  Start")
+        );
+    }
+
+    #[test]
+    fn replayed() {
+        let input = "R250\nL50\nL1\nR1\nL0\nR100\nL199\nR7";
+        let rotations = rotations(input, Parsing::Strict).unwrap();
+        for dial in [
+            Dial::default(),
+            Dial::new(1, 0, [0]).unwrap(),
+            Dial::new(7, 3, [0, 3, 5]).unwrap(),
+            Dial::new(100, 0, [0]).unwrap(),
+        ] {
+            assert_eq!(trace(dial.clone(), &rotations), replay(dial, &rotations));
         }
     }
 
-    lock.zeroes
+    #[test]
+    fn strict() {
+        let error = |input, parsing| match rotations(input, parsing) {
+            Err(Error::Parse {
+                line,
+                column,
+                text,
+                expected,
+            }) => (line, column, text, expected),
+            other => panic!("expected a parse error, got {other:?}"),
+        };
+
+        assert_eq!(
+            error("L68\nX30\n", Parsing::Strict),
+            (2, 1, "X".into(), "`L` or `R`".into())
+        );
+        assert_eq!(error("L68\nR\n", Parsing::Strict).3, "a number");
+        assert_eq!(error("L68\n\nR3\n", Parsing::Strict).0, 2);
+        assert_eq!(error("L68\r\nR3\r\n", Parsing::Strict).0, 1);
+        assert_eq!(error("L6 8\n", Parsing::Lenient).3, "a digit");
+        assert!(
+            error("L99999999999999999999\n", Parsing::Lenient)
+                .3
+                .contains("64 bits")
+        );
+        assert!(part_one("L68\nL3O\n").is_err());
+
+        assert_eq!(
+            rotations("\r\nL68\r\n \nR5000000000\r\n", Parsing::Lenient).unwrap(),
+            [(2, Rotation::Left(68)), (4, Rotation::Right(5_000_000_000))]
+        );
+        assert_eq!(rotations("", Parsing::Strict).unwrap(), []);
+    }
 }
@@ -1,69 +1,35 @@
-use std::{collections::HashMap, fmt::Display};
+use super::manifold;
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+};
+use std::collections::HashMap;
 
-struct Field {
-    data: Vec<u8>,
-    width: usize,
-    height: usize,
-}
-
-impl Field {
-    pub fn new(input: &str) -> Self {
-        let width = input.as_bytes().iter().position(|c| *c == b'\n').unwrap() + 1;
-        let height = input.len() / width;
-
-        Self {
-            data: input.as_bytes().to_vec(),
-            width,
-            height: height + 1,
-        }
-    }
-
-    pub fn get(&self, x: usize, y: usize) -> u8 {
-        self.data[x + y * self.width]
-    }
-
-    pub fn set(&mut self, x: usize, y: usize, value: u8) {
-        self.data[x + y * self.width] = value;
-    }
-}
-
-impl core::fmt::Display for Field {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            let start = y * self.width;
-            let end = start + self.width - 1;
-
-            write!(
-                f,
-                "{}\n",
-                std::str::from_utf8(&self.data[start..end]).unwrap()
-            )?;
-        }
-
-        Ok(())
-    }
-}
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(7, Part::One, "first_try", part_one),
+    &Solver::new(7, Part::Two, "first_try", part_two),
+];
 
-pub fn part_one(input: &str) -> impl Display {
-    let mut field = Field::new(input);
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let mut field = manifold(input)?;
 
     // set first beam
-    for x in 0..field.width - 1 {
-        if field.data[x] == b'S' {
-            field.data[x + field.width] = b'|'
+    for x in 0..field.width() {
+        if field[(x, 0)] == b'S' {
+            field[(x, 1)] = b'|';
         }
     }
 
     let mut splits = 0;
-    for y in 2..field.height {
-        for x in 0..field.width - 1 {
-            match (field.get(x, y - 1), field.get(x, y)) {
+    for y in 2..field.height() {
+        for x in 0..field.width() {
+            match (field[(x, y - 1)], field[(x, y)]) {
                 (b'|', b'.') => {
-                    field.set(x, y, b'|');
+                    field[(x, y)] = b'|';
                 }
                 (b'|', b'^') => {
-                    field.set(x - 1, y, b'|');
-                    field.set(x + 1, y, b'|');
+                    field[(x - 1, y)] = b'|';
+                    field[(x + 1, y)] = b'|';
                     splits += 1;
                 }
                 _ => {}
@@ -71,7 +37,7 @@ pub fn part_one(input: &str) -> impl Display {
         }
     }
 
-    splits
+    Ok(splits.into())
 }
 
 #[derive(Debug)]
@@ -80,24 +46,24 @@ struct Node {
     children: Vec<usize>,
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     let mut tree: Vec<Node> = Vec::new();
-    let mut field = Field::new(input);
+    let mut field = manifold(input)?;
 
     // set first beam
-    for x in 0..field.width - 1 {
-        if field.data[x] == b'S' {
+    for x in 0..field.width() {
+        if field[(x, 0)] == b'S' {
             tree.push(Node {
                 position: (x, 1),
                 children: Vec::new(),
             });
-            field.data[x + field.width] = b'|'
+            field[(x, 1)] = b'|';
         }
     }
 
-    for y in 2..field.height {
-        for x in 0..field.width - 1 {
-            match (field.get(x, y - 1), field.get(x, y)) {
+    for y in 2..field.height() {
+        for x in 0..field.width() {
+            match (field[(x, y - 1)], field[(x, y)]) {
                 (b'|', b'.') => {
                     let len = tree.len();
                     let parent = tree
@@ -109,7 +75,7 @@ pub fn part_two(input: &str) -> impl Display {
                         position: (x, y),
                         children: Vec::new(),
                     });
-                    field.set(x, y, b'|');
+                    field[(x, y)] = b'|';
                 }
                 (b'|', b'|') => {
                     let parent_index = tree
@@ -128,7 +94,7 @@ pub fn part_two(input: &str) -> impl Display {
                         .position(|n| n.position.0 == x && n.position.1 == y - 1)
                         .unwrap();
 
-                    if field.get(x + 1, y) == b'|' {
+                    if field[(x + 1, y)] == b'|' {
                         let existing_child = tree
                             .iter()
                             .position(|n| n.position.0 == x + 1 && n.position.1 == y)
@@ -143,7 +109,7 @@ pub fn part_two(input: &str) -> impl Display {
                         });
                     }
 
-                    if field.get(x - 1, y) == b'|' {
+                    if field[(x - 1, y)] == b'|' {
                         let existing_child = tree
                             .iter()
                             .position(|n| n.position.0 == x - 1 && n.position.1 == y)
@@ -158,8 +124,8 @@ pub fn part_two(input: &str) -> impl Display {
                         });
                     }
 
-                    field.set(x - 1, y, b'|');
-                    field.set(x + 1, y, b'|');
+                    field[(x - 1, y)] = b'|';
+                    field[(x + 1, y)] = b'|';
                 }
                 _ => {}
             }
@@ -191,7 +157,7 @@ pub fn part_two(input: &str) -> impl Display {
         }
     }
 
-    descend_tree(&tree, 0, &mut HashMap::default())
+    Ok(descend_tree(&tree, 0, &mut HashMap::default()).into())
 }
 
 #[cfg(test)]
@@ -201,12 +167,12 @@ mod test {
     #[test]
     fn tree() {
         let input = include_str!("../../../inputs/seven_test.txt");
-        assert_eq!("21", part_one(input).to_string().as_str());
+        assert_eq!(part_one(input).unwrap(), 21);
     }
 
     #[test]
     fn tree_2() {
         let input = include_str!("../../../inputs/seven_test.txt");
-        assert_eq!("40", part_two(input).to_string().as_str());
+        assert_eq!(part_two(input).unwrap(), 40);
     }
 }
@@ -1,2 +1,37 @@
+use crate::{Error, grid::Grid, parse};
+
 pub mod first_try;
 pub mod second_try;
+
+/// Read the manifold, a rectangle of `.`, `^` and `S`, checking the
+/// start is on the first row and there are no splitters on the edges.
+pub(super) fn manifold(input: &str) -> Result<Grid<u8>, Error> {
+    let input = input.trim_end_matches('\n').as_bytes();
+    let grid = Grid::parse(
+        input,
+        |c| matches!(c, b'.' | b'^' | b'S').then_some(c),
+        "`.`, `^` or `S`",
+    )?;
+
+    let first = input.split(|c| *c == b'\n').next().unwrap_or_default();
+    if !first.contains(&b'S') {
+        return Err(Error::parse(input, first, "a start `S` on the first row"));
+    }
+    if grid.height() < 2 {
+        return Err(Error::parse(
+            input,
+            &input[input.len()..],
+            "at least two rows",
+        ));
+    }
+
+    for row in parse::lines(input).filter(|row| !row.is_empty()) {
+        for i in [0, row.bytes.len() - 1] {
+            if row.bytes[i] == b'^' {
+                return Err(row.error(&row.bytes[i..=i], "no splitters on the edges"));
+            }
+        }
+    }
+
+    Ok(grid)
+}
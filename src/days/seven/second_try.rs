@@ -1,58 +1,49 @@
-use std::{collections::HashMap, fmt::Display};
-
+use super::manifold;
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    grid::Grid,
+};
+use std::collections::HashMap;
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(7, Part::One, "second_try", part_one),
+    &Solver::new(7, Part::Two, "second_try", part_two),
+];
+
+/// The manifold, with its size small enough for `u16` positions.
 struct Field {
-    data: Vec<u8>,
+    grid: Grid<u8>,
     width: u16,
     height: u16,
 }
 
 impl Field {
-    pub fn new(input: &str) -> Self {
-        let width = input.as_bytes().iter().position(|c| *c == b'\n').unwrap() + 1;
-        let height = input.len() / width;
-
-        Self {
-            data: input.as_bytes().to_vec(),
-            width: width as u16,
-            height: (height + 1) as u16,
-        }
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let grid = manifold(input)?;
+
+        let too_large = || Error::Failed("a manifold this large doesn't fit in `u16`".into());
+        Ok(Self {
+            width: grid.width().try_into().map_err(|_| too_large())?,
+            height: grid.height().try_into().map_err(|_| too_large())?,
+            grid,
+        })
     }
 
     pub fn get(&self, x: u16, y: u16) -> u8 {
-        let x = x as usize;
-        let y = y as usize;
-        self.data[x + y * self.width as usize]
+        self.grid[(x as usize, y as usize)]
     }
 
     pub fn set(&mut self, x: u16, y: u16, value: u8) {
-        let x = x as usize;
-        let y = y as usize;
-        self.data[x + y * self.width as usize] = value;
-    }
-}
-
-impl core::fmt::Display for Field {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            let start = y * self.width;
-            let end = start + self.width - 1;
-
-            write!(
-                f,
-                "{}\n",
-                std::str::from_utf8(&self.data[start as usize..end as usize]).unwrap()
-            )?;
-        }
-
-        Ok(())
+        self.grid[(x as usize, y as usize)] = value;
     }
 }
 
-pub fn part_one(input: &str) -> impl Display {
-    let mut field = Field::new(input);
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let mut field = Field::new(input)?;
 
     // set first beam
-    for x in 0..field.width - 1 {
+    for x in 0..field.width {
         if field.get(x, 0) == b'S' {
             field.set(x, 1, b'|');
         }
@@ -60,7 +51,7 @@ pub fn part_one(input: &str) -> impl Display {
 
     let mut splits = 0;
     for y in 2..field.height {
-        for x in 0..field.width - 1 {
+        for x in 0..field.width {
             match (field.get(x, y - 1), field.get(x, y)) {
                 (b'|', b'.') => {
                     field.set(x, y, b'|');
@@ -75,7 +66,7 @@ pub fn part_one(input: &str) -> impl Display {
         }
     }
 
-    splits
+    Ok(splits.into())
 }
 
 #[derive(Debug, Default)]
@@ -84,13 +75,13 @@ struct Node {
     children: Vec<(u16, u16)>,
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     let mut tree: HashMap<(u16, u16), Node> = HashMap::new();
-    let mut field = Field::new(input);
+    let mut field = Field::new(input)?;
 
     // set first beam
     let mut first_node = (0u16, 0u16);
-    for x in 0..field.width - 1 {
+    for x in 0..field.width {
         if field.get(x, 0) == b'S' {
             field.set(x, 1, b'|');
             tree.insert(
@@ -104,11 +95,11 @@ pub fn part_two(input: &str) -> impl Display {
     }
 
     for y in 2..field.height {
-        for x in 0..field.width - 1 {
+        for x in 0..field.width {
             match (field.get(x, y - 1), field.get(x, y)) {
                 (b'|', b'.') => {
                     let parent = tree.get_mut(&(x, y - 1)).unwrap();
-                    parent.children.push((x as u16, y as u16));
+                    parent.children.push((x, y));
                     tree.entry((x, y)).or_default();
                     field.set(x, y, b'|');
                 }
@@ -160,7 +151,7 @@ pub fn part_two(input: &str) -> impl Display {
         }
     }
 
-    descend_tree(&tree, first_node, &mut HashMap::default())
+    Ok(descend_tree(&tree, first_node, &mut HashMap::default()).into())
 }
 
 #[cfg(test)]
@@ -170,12 +161,12 @@ mod test {
     #[test]
     fn tree() {
         let input = include_str!("../../../inputs/seven_test.txt");
-        assert_eq!("21", part_one(input).to_string().as_str());
+        assert_eq!(part_one(input).unwrap(), 21);
     }
 
     #[test]
     fn tree_2() {
         let input = include_str!("../../../inputs/seven_test.txt");
-        assert_eq!("40", part_two(input).to_string().as_str());
+        assert_eq!(part_two(input).unwrap(), 40);
     }
 }
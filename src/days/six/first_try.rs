@@ -1,4 +1,13 @@
-use std::fmt::Display;
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(6, Part::One, "first_try", part_one),
+    &Solver::new(6, Part::Two, "first_try", part_two),
+];
 
 #[derive(Debug)]
 enum Operator {
@@ -11,96 +20,41 @@ enum Row {
     Operators(Vec<Operator>),
 }
 
-fn advance_by(input: &mut &[u8], bytes: usize) {
-    *input = &mut &input[bytes..];
-}
-
-fn parse_ascii_number(number: &mut &[u8]) -> i64 {
-    let mut value = 0;
-
-    while matches!(number[0], b'0'..=b'9') {
-        value *= 10;
-        value += (number[0] - b'0') as i64;
-        advance_by(number, 1);
-        if number.is_empty() {
-            break;
-        }
+fn parse_operator(source: &[u8], word: &[u8]) -> Result<Operator, Error> {
+    match word {
+        b"+" => Ok(Operator::Add),
+        b"*" => Ok(Operator::Mult),
+        _ => Err(Error::parse(source, word, "`+` or `*`")),
     }
-
-    value
 }
 
-fn parse_number(input: &mut &[u8]) -> Option<i64> {
-    if input.is_empty() {
-        return None;
-    }
-
-    while input[0] == b' ' {
-        advance_by(input, 1);
-        if input.is_empty() {
-            return None;
-        }
-    }
-
-    Some(parse_ascii_number(input))
-}
-
-fn parse_operator(input: &mut &[u8]) -> Option<Operator> {
-    while input[0] == b' ' {
-        advance_by(input, 1);
-        if input.is_empty() {
-            return None;
-        }
-    }
-
-    let operator = match input[0] {
-        b'+' => {
-            advance_by(input, 1);
-            Operator::Add
-        }
-        b'*' => {
-            advance_by(input, 1);
-            Operator::Mult
-        }
-        _ => return None,
-    };
-
-    Some(operator)
-}
-
-fn parse_line(mut input: &[u8]) -> Row {
+fn parse_line(source: &[u8], input: &[u8]) -> Result<Row, Error> {
     // probe the line
     let is_values = input
         .iter()
         .find_map(|c| match *c {
             b'*' | b'+' => Some(false),
-            b'0'..b'9' => Some(true),
+            b'0'..=b'9' => Some(true),
             _ => None,
         })
-        .unwrap();
+        .ok_or_else(|| Error::parse(source, input, "a row of numbers or operators"))?;
 
     if is_values {
-        let mut values = Vec::new();
-        let input = &mut input;
-        while let Some(number) = parse_number(input) {
-            values.push(number)
-        }
-        Row::Values(values)
+        Ok(Row::Values(parse::spaced(source, input)?))
     } else {
-        let mut ops = Vec::new();
-        let input = &mut input;
-        while let Some(number) = parse_operator(input) {
-            ops.push(number)
-        }
-        Row::Operators(ops)
+        let ops = parse::words(input)
+            .map(|word| parse_operator(source, word))
+            .collect::<Result<_, _>>()?;
+        Ok(Row::Operators(ops))
     }
 }
 
-pub fn part_one(input: &str) -> impl Display {
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    let source = input.as_bytes();
     let mut values = Vec::new();
     let mut operators = Vec::new();
-    for line in input.as_bytes().split(|c| *c == b'\n').map(parse_line) {
-        match line {
+    for line in source.split(|c| *c == b'\n').filter(|l| !l.is_empty()) {
+        match parse_line(source, line)? {
             Row::Values(v) => values.push(v),
             Row::Operators(mut o) => {
                 operators.append(&mut o);
@@ -108,27 +62,34 @@ pub fn part_one(input: &str) -> impl Display {
         }
     }
 
+    let columns = operators.len();
+    if values.is_empty() || values.iter().any(|row| row.len() != columns) {
+        return Err(Error::Failed(format!(
+            "expected rows of {columns} numbers, one for each operator"
+        )));
+    }
+
     let mut total = 0;
-    for col in 0..values[0].len() {
+    for col in 0..columns {
         match operators[col] {
             Operator::Add => {
                 let mut value = 0;
-                for j in 0..values.len() {
-                    value += values[j][col];
+                for row in &values {
+                    value += row[col];
                 }
                 total += value;
             }
             Operator::Mult => {
                 let mut value = 1;
-                for j in 0..values.len() {
-                    value *= values[j][col];
+                for row in &values {
+                    value *= row[col];
                 }
                 total += value;
             }
         }
     }
 
-    total
+    Ok(total.into())
 }
 
 #[derive(Debug)]
@@ -146,19 +107,19 @@ fn op(op: u8) -> Option<Operator> {
 }
 
 // what was even the point of all the above???
-fn column_spans(input: &[u8]) -> (usize, Vec<Column>) {
+fn column_spans(input: &[u8]) -> Result<(usize, Vec<Column>), Error> {
     let mut columns = Vec::new();
 
     // find the first operator
     let op_index = input
         .iter()
         .position(|c| matches!(*c, b'*' | b'+'))
-        .unwrap();
+        .ok_or_else(|| Error::parse(input, &input[input.len()..], "a row of operators"))?;
 
     let mut last_index = 0;
-    let mut current_op = op(input[op_index]).unwrap();
-    for i in op_index + 1..input.len() {
-        if let Some(op) = op(input[i]) {
+    let mut current_op = op(input[op_index]).expect("found an operator");
+    for (i, byte) in input.iter().enumerate().skip(op_index + 1) {
+        if let Some(op) = op(*byte) {
             columns.push(Column {
                 range: (last_index, i - op_index),
                 operator: current_op,
@@ -174,14 +135,22 @@ fn column_spans(input: &[u8]) -> (usize, Vec<Column>) {
         operator: current_op,
     });
 
-    (op_index, columns)
+    Ok((op_index, columns))
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     let input = input.as_bytes();
 
-    let (op_start, columns) = column_spans(input);
-    let lines = input[..op_start - 1]
+    let (op_start, columns) = column_spans(input)?;
+    let lines = input[..op_start]
+        .strip_suffix(b"\n")
+        .ok_or_else(|| {
+            Error::parse(
+                input,
+                &input[op_start..op_start + 1],
+                "operators on their own line, below the numbers",
+            )
+        })?
         .split(|c| *c == b'\n')
         .collect::<Vec<_>>();
 
@@ -194,18 +163,16 @@ pub fn part_two(input: &str) -> impl Display {
         let mut values = Vec::new();
         // scan rows in reverse order
         for col in (start..end).rev() {
-            let mut number: i64 = 0;
-            let mut any = false;
-
-            for line in &lines {
-                if matches!(line[col], b'0'..=b'9') {
-                    number *= 10;
-                    number += (line[col] - b'0') as i64;
-                    any = true;
-                }
-            }
-
-            if any {
+            let mut digits = lines
+                .iter()
+                .filter_map(|line| line.get(col).copied())
+                .filter(u8::is_ascii_digit)
+                .peekable();
+
+            if digits.peek().is_some() {
+                let number = parse::digits::<i64>(digits).ok_or_else(|| {
+                    Error::Failed(format!("column {} doesn't fit in 64 bits", col + 1))
+                })?;
                 values.push(number);
             }
         }
@@ -224,7 +191,7 @@ pub fn part_two(input: &str) -> impl Display {
         }
     }
 
-    total
+    Ok(total.into())
 }
 
 #[cfg(test)]
@@ -233,13 +200,13 @@ mod test {
 
     #[test]
     fn test_six() {
-        let input = include_str!("../../../inputs/example.txt");
-        assert_eq!("4277556", part_one(input).to_string().as_str());
+        let input = include_str!("../../../inputs/six_test.txt");
+        assert_eq!(part_one(input).unwrap(), 4277556);
     }
 
     #[test]
     fn test_six_two() {
-        let input = include_str!("../../../inputs/example.txt");
-        assert_eq!("3263827", part_two(input).to_string().as_str());
+        let input = include_str!("../../../inputs/six_test.txt");
+        assert_eq!(part_two(input).unwrap(), 3263827);
     }
 }
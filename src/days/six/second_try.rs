@@ -1,46 +1,68 @@
-use std::fmt::Display;
-
-pub fn part_one(input: &str) -> impl Display {
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(6, Part::One, "second_try", part_one),
+    &Solver::new(6, Part::Two, "second_try", part_two),
+];
+
+pub fn part_one(input: &str) -> Result<Answer, Error> {
     let mut result = 0;
 
+    let input = input.as_bytes();
     let mut number_rows = Vec::new();
-    for line in input.as_bytes().split(|c| *c == b'\n') {
+    for line in input.split(|c| *c == b'\n').filter(|l| !l.is_empty()) {
         if !matches!(line[0], b'*' | b'+') {
-            number_rows.push(parse_numbers(line).collect::<Vec<_>>());
+            number_rows.push(parse::spaced::<i64>(input, line)?);
         } else {
-            for (col, operator) in parse_operators(line).enumerate() {
+            let operators = parse_operators(input, line).collect::<Result<Vec<_>, _>>()?;
+            if number_rows.iter().any(|v| v.len() != operators.len()) {
+                return Err(Error::parse(
+                    input,
+                    line,
+                    "one operator for each column of numbers",
+                ));
+            }
+
+            for (col, operator) in operators.into_iter().enumerate() {
                 result += operator.execute(number_rows.iter().map(|v| v[col]));
             }
         }
     }
 
-    result
+    Ok(result.into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     let mut result = 0;
 
-    let input = input.as_bytes();
+    // the trailing spaces matter, but a trailing newline doesn't
+    let input = input.trim_end_matches('\n').as_bytes();
     let lines = input.split(|c| *c == b'\n').collect::<Vec<_>>();
-    let operator_line = lines.last().unwrap();
+    let operator_line = lines.last().expect("split always yields a line");
 
     let mut numbers = Vec::new();
     for column in (0..lines[0].len()).rev() {
         let mut digits = ColumnIter::new(&lines[..lines.len() - 1], column)
-            .filter(|c| matches!(*c, b'0'..=b'9'))
+            .filter(|c: &u8| c.is_ascii_digit())
             .peekable();
         if digits.peek().is_none() {
             continue;
         }
 
-        numbers.push(parse_number(digits));
+        numbers.push(parse::digits(digits).ok_or_else(|| {
+            Error::Failed(format!("column {} doesn't fit in 64 bits", column + 1))
+        })?);
 
-        if let Some(op) = parse_operator(operator_line[column]) {
+        if let Some(op) = operator_line.get(column).copied().and_then(parse_operator) {
             result += op.execute(numbers.drain(..));
         }
     }
 
-    result
+    Ok(result.into())
 }
 
 struct ColumnIter<'a> {
@@ -79,28 +101,11 @@ impl Operator {
     fn execute(&self, values: impl Iterator<Item = i64>) -> i64 {
         match self {
             Self::Add => values.sum::<i64>(),
-            Self::Mult => values.fold(1, |accum, v| accum * v),
+            Self::Mult => values.product(),
         }
     }
 }
 
-fn split_whitespace(seq: &[u8]) -> impl Iterator<Item = &'_ [u8]> {
-    seq.split(|c| *c == b' ').filter(|seq| !seq.is_empty())
-}
-
-fn parse_number(bytes: impl Iterator<Item = u8>) -> i64 {
-    let mut value = 0;
-    for byte in bytes {
-        value *= 10;
-        value += (byte - b'0') as i64;
-    }
-    value
-}
-
-fn parse_numbers(line: &[u8]) -> impl Iterator<Item = i64> {
-    split_whitespace(line).map(|seq| parse_number(seq.iter().copied()))
-}
-
 fn parse_operator(byte: u8) -> Option<Operator> {
     match byte {
         b'*' => Some(Operator::Mult),
@@ -109,8 +114,14 @@ fn parse_operator(byte: u8) -> Option<Operator> {
     }
 }
 
-fn parse_operators(line: &[u8]) -> impl Iterator<Item = Operator> {
-    split_whitespace(line).flat_map(|seq| parse_operator(seq[0]))
+fn parse_operators<'a>(
+    input: &'a [u8],
+    line: &'a [u8],
+) -> impl Iterator<Item = Result<Operator, Error>> {
+    parse::words(line).map(|seq| match seq {
+        &[op] => parse_operator(op).ok_or_else(|| Error::parse(input, seq, "`+` or `*`")),
+        _ => Err(Error::parse(input, seq, "`+` or `*`")),
+    })
 }
 
 #[cfg(test)]
@@ -119,13 +130,13 @@ mod test {
 
     #[test]
     fn six_p1() {
-        let input = include_str!("../../../inputs/example.txt");
-        assert_eq!("4277556", part_one(input).to_string().as_str());
+        let input = include_str!("../../../inputs/six_test.txt");
+        assert_eq!(part_one(input).unwrap(), 4277556);
     }
 
     #[test]
     fn six_p2() {
-        let input = include_str!("../../../inputs/example.txt");
-        assert_eq!("3263827", part_two(input).to_string().as_str());
+        let input = include_str!("../../../inputs/six_test.txt");
+        assert_eq!(part_two(input).unwrap(), 3263827);
     }
 }
@@ -1,9 +1,18 @@
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
 use std::{
     cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
-    fmt::Display,
+    collections::{BinaryHeap, HashMap},
 };
 
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(10, Part::One, "default", part_one),
+    &Solver::new(10, Part::Two, "default", part_two),
+];
+
 #[derive(Debug)]
 struct Machine {
     target_lights: Vec<bool>,
@@ -18,7 +27,7 @@ fn increment_joltage(buttons: &[usize], joltage: &mut [usize]) {
 }
 
 impl Machine {
-    fn exhaustive_light_solution(&self) -> usize {
+    fn exhaustive_light_solution(&self) -> Result<usize, Error> {
         fn toggle_lights(buttons: &[usize], lights: &mut [bool]) {
             for button in buttons {
                 let value = lights[*button];
@@ -38,7 +47,7 @@ impl Machine {
                     toggle_lights(button, &mut new_state);
 
                     if new_state == self.target_lights {
-                        return step;
+                        return Ok(step);
                     }
 
                     states_out.push(new_state);
@@ -47,10 +56,26 @@ impl Machine {
 
             core::mem::swap(&mut states_out, &mut states_in);
             step += 1;
+
+            // Pressing a button twice undoes it, so no
+            // solution needs more presses than there are buttons.
+            if step > self.buttons.len() {
+                return Err(Error::Failed(format!(
+                    "no buttons light up `{}`",
+                    self.lights_diagram()
+                )));
+            }
         }
     }
 
-    fn a_star_joltage(&self) -> usize {
+    fn lights_diagram(&self) -> String {
+        self.target_lights
+            .iter()
+            .map(|on| if *on { '#' } else { '.' })
+            .collect()
+    }
+
+    fn a_star_joltage(&self) -> Result<usize, Error> {
         #[derive(PartialEq, Eq, Clone, PartialOrd, Ord, Hash, Debug)]
         struct Position(Vec<usize>);
 
@@ -68,7 +93,7 @@ impl Machine {
 
         impl PartialOrd for OpenNode {
             fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                Some(other.score.cmp(&self.score))
+                Some(self.cmp(other))
             }
         }
 
@@ -113,14 +138,14 @@ impl Machine {
         }
 
         while let Some(OpenNode {
-            score,
+            score: _,
             position: current_node,
         }) = open_set.pop()
         {
             if current_node.0 == self.joltage {
                 let path = reconstruct_path(&came_from, &current_node);
                 // println!("{path:#?}");
-                return path.len() - 1;
+                return Ok(path.len() - 1);
             }
 
             // println!("current_node: {:?}", current_node.0);
@@ -149,10 +174,14 @@ impl Machine {
             }
         }
 
-        panic!("Failed to find path");
+        Err(Error::Failed(format!(
+            "no button presses reach the joltages {:?}",
+            self.joltage
+        )))
     }
 
     // naive DFS and BFS are too slow
+    #[allow(dead_code)]
     fn exhaustive_joltage_solution(&mut self) -> usize {
         // DFS
         self.buttons.sort_by_key(|b| Reverse(b.len()));
@@ -175,7 +204,7 @@ impl Machine {
         }
 
         let initial_state = vec![0; self.joltage.len()];
-        dfs(&self, 1, &initial_state).unwrap()
+        dfs(self, 1, &initial_state).unwrap()
 
         // let mut step = 1;
         // loop {
@@ -200,16 +229,22 @@ impl Machine {
         // }
     }
 
-    fn parse_line(line: &str) -> Self {
+    fn parse_line(input: &str, line: &str) -> Result<Self, Error> {
+        let invalid = |text: &str, expected: String| {
+            Error::parse(input.as_bytes(), text.as_bytes(), expected)
+        };
         let mut parts = line.split(' ');
 
-        let lights = parts.next().unwrap();
+        let lights = parts.next().unwrap_or_default();
+        let Some(lights_inner) = lights.strip_prefix('[').and_then(|l| l.strip_suffix(']')) else {
+            return Err(invalid(lights, "lights like `[.##.]`".into()));
+        };
         let mut target_lights = Vec::new();
-        for char in lights[1..lights.len() - 1].chars() {
+        for (i, char) in lights_inner.char_indices() {
             match char {
                 '.' => target_lights.push(false),
                 '#' => target_lights.push(true),
-                _ => unreachable!(),
+                _ => return Err(invalid(&lights_inner[i..], "`.` or `#`".into())),
             }
         }
 
@@ -217,49 +252,65 @@ impl Machine {
         let mut buttons = Vec::new();
 
         for part in parts {
-            match part.chars().next().unwrap() {
-                '(' => {
-                    let numbers = part[1..part.len() - 1]
-                        .split(',')
-                        .map(|s| s.parse().unwrap())
-                        .collect();
-                    buttons.push(numbers);
+            if let Some(inner) = part.strip_prefix('(').and_then(|p| p.strip_suffix(')')) {
+                let button: Vec<usize> = parse::list(input.as_bytes(), inner.as_bytes(), b',')?;
+                if button.iter().any(|index| *index >= target_lights.len()) {
+                    let expected = format!("lights below {}", target_lights.len());
+                    return Err(invalid(inner, expected));
                 }
-                '{' => {
-                    let numbers = part[1..part.len() - 1]
-                        .split(',')
-                        .map(|s| s.parse::<usize>().unwrap());
-                    joltage.extend(numbers);
-                }
-                _ => unreachable!(),
+                buttons.push(button);
+            } else if let Some(inner) = part.strip_prefix('{').and_then(|p| p.strip_suffix('}')) {
+                joltage.extend(parse::list::<usize>(
+                    input.as_bytes(),
+                    inner.as_bytes(),
+                    b',',
+                )?);
+            } else {
+                return Err(invalid(
+                    part,
+                    "a button like `(1,3)` or joltages like `{3,5}`".into(),
+                ));
             }
         }
 
-        Machine {
+        if joltage.len() != target_lights.len() {
+            let expected = format!("{} joltages", target_lights.len());
+            return Err(invalid(line, expected));
+        }
+
+        Ok(Machine {
             target_lights,
             buttons,
             joltage,
-        }
+        })
     }
 }
 
-pub fn part_one(input: &str) -> impl Display {
+pub fn part_one(input: &str) -> Result<Answer, Error> {
     input
         .lines()
-        .map(Machine::parse_line)
-        .map(|machine| machine.exhaustive_light_solution())
-        .inspect(|value| println!("value: {value}"))
-        .sum::<usize>()
+        .map(|line| Machine::parse_line(input, line)?.exhaustive_light_solution())
+        .inspect(|value| {
+            if let Ok(value) = value {
+                log::debug!("value: {value}")
+            }
+        })
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     input
         .lines()
-        .map(Machine::parse_line)
         // .map(|mut machine| machine.exhaustive_joltage_solution())
-        .map(|machine| machine.a_star_joltage())
-        .inspect(|value| println!("value: {value}"))
-        .sum::<usize>()
+        .map(|line| Machine::parse_line(input, line)?.a_star_joltage())
+        .inspect(|value| {
+            if let Ok(value) = value {
+                log::debug!("value: {value}")
+            }
+        })
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
 }
 
 #[cfg(test)]
@@ -269,12 +320,25 @@ mod test {
     #[test]
     fn ten() {
         let input = include_str!("../../inputs/ten_test.txt");
-        assert_eq!("7", part_one(input).to_string().as_str());
+        assert_eq!(part_one(input).unwrap(), 7);
     }
 
     #[test]
     fn ten2() {
         let input = include_str!("../../inputs/ten_test.txt");
-        assert_eq!("33", part_two(input).to_string().as_str());
+        assert_eq!(part_two(input).unwrap(), 33);
+    }
+
+    #[test]
+    fn ten_malformed() {
+        assert!(matches!(
+            part_one("[.#] (0) (2) {1,2}"),
+            Err(Error::Parse {
+                line: 1,
+                column: 11,
+                ..
+            })
+        ));
+        assert!(matches!(part_one("[.#] (0) {1,2}"), Err(Error::Failed(_))));
     }
 }
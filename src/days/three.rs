@@ -1,4 +1,13 @@
-use std::fmt::Display;
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse,
+};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(3, Part::One, "default", part_one),
+    &Solver::new(3, Part::Two, "default", part_two),
+];
 
 fn digit_from_ascii(byte: u8) -> usize {
     (byte - b'0') as usize
@@ -31,22 +40,36 @@ fn max_n_digits(line: &[u8], digits: usize) -> usize {
     digit_from_ascii(max_value) * 10usize.pow(digits as u32 - 1) + rest
 }
 
-pub fn part_one(input: &str) -> impl Display {
-    let bytes = input.as_bytes();
+/// Split the input into banks, checking each has at least `digits` digits.
+fn banks(input: &str, digits: usize) -> impl Iterator<Item = Result<&[u8], Error>> {
+    parse::lines(input.as_bytes())
+        .filter(|line| !line.is_empty())
+        .map(move |line| {
+            let bank = line.bytes;
+            if let Some(i) = bank.iter().position(|c| !c.is_ascii_digit()) {
+                return Err(line.error(&bank[i..=i], "a digit"));
+            }
+
+            if bank.len() < digits {
+                return Err(line.error(bank, format!("at least {digits} digits")));
+            }
 
-    bytes
-        .split(|c| *c == b'\n')
-        .map(|line| max_n_digits(line, 2))
-        .sum::<usize>()
+            Ok(bank)
+        })
 }
 
-pub fn part_two(input: &str) -> impl Display {
-    let bytes = input.as_bytes();
+pub fn part_one(input: &str) -> Result<Answer, Error> {
+    banks(input, 2)
+        .map(|line| line.map(|line| max_n_digits(line, 2)))
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
+}
 
-    bytes
-        .split(|c| *c == b'\n')
-        .map(|line| max_n_digits(line, 12))
-        .sum::<usize>()
+pub fn part_two(input: &str) -> Result<Answer, Error> {
+    banks(input, 12)
+        .map(|line| line.map(|line| max_n_digits(line, 12)))
+        .sum::<Result<usize, _>>()
+        .map(Answer::from)
 }
 
 #[cfg(test)]
@@ -66,4 +89,18 @@ mod test {
         assert_eq!(max_n_digits(b"515", 2), 55);
         assert_eq!(max_n_digits(b"655006", 3), 656);
     }
+
+    #[test]
+    fn malformed() {
+        assert!(matches!(
+            part_one("12\n3x4"),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(part_two("1234").is_err());
+        assert_eq!(part_one("1234\n\n").unwrap().to_string(), "34");
+    }
 }
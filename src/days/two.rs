@@ -1,13 +1,28 @@
-use std::fmt::Display;
-
-fn parse_ranges(input: &str) -> impl Iterator<Item = (usize, usize)> {
-    input.split(',').flat_map(|range| {
-        let mut pair = range.split('-');
-        let left = pair.next()?.parse().ok()?;
-        let right = pair.next()?.parse().ok()?;
-
-        Some((left, right))
-    })
+use crate::{
+    Answer, Error,
+    days::{Part, Solution, Solver},
+    parse, scan,
+};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new(2, Part::One, "threaded", part_one),
+    &Solver::new(2, Part::One, "arithmetic", part_one_arithmetic),
+    &Solver::new(2, Part::One, "pooled", part_one_pooled),
+    &Solver::new(2, Part::One, "simple", part_one_simple),
+    &Solver::new(2, Part::Two, "threaded", part_two),
+    &Solver::new(2, Part::Two, "arithmetic", part_two_arithmetic),
+    &Solver::new(2, Part::Two, "pooled", part_two_pooled),
+    &Solver::new(2, Part::Two, "simple", part_two_simple),
+];
+
+/// Parse the comma separated ID ranges, like `11-22,95-115`.
+fn parse_ranges(input: &str) -> Result<Vec<(usize, usize)>, Error> {
+    let source = input.as_bytes();
+    let ranges = input.trim_end().as_bytes();
+    ranges
+        .split(|c| *c == b',')
+        .map(|range| parse::range(source, range))
+        .collect()
 }
 
 /// Push the ascii digits of an integer to a buffer in reverse order.
@@ -44,11 +59,11 @@ fn is_invalid_id(id: usize, buffer: &mut Vec<u8>) -> bool {
     digits[..half] == digits[half..]
 }
 
-pub fn part_one_simple(input: &str) -> impl Display {
+pub fn part_one_simple(input: &str) -> Result<Answer, Error> {
     let mut sum = 0;
     let mut buffer = Vec::with_capacity(16);
 
-    for (start, end) in parse_ranges(input) {
+    for (start, end) in parse_ranges(input)? {
         for i in start..=end {
             if is_invalid_id(i, &mut buffer) {
                 sum += i;
@@ -57,13 +72,13 @@ pub fn part_one_simple(input: &str) -> impl Display {
         }
     }
 
-    sum
+    Ok(sum.into())
 }
 
-pub fn part_one(input: &str) -> impl Display {
+pub fn part_one(input: &str) -> Result<Answer, Error> {
     let (tx, rx) = std::sync::mpsc::sync_channel(16);
 
-    for (start, end) in parse_ranges(input) {
+    for (start, end) in parse_ranges(input)? {
         let tx = tx.clone();
         std::thread::spawn(move || {
             let mut buffer = Vec::with_capacity(16);
@@ -83,7 +98,23 @@ pub fn part_one(input: &str) -> impl Display {
     // we'll use the hangup as a termination signal
     drop(tx);
 
-    rx.iter().sum::<usize>()
+    Ok(rx.iter().sum::<usize>().into())
+}
+
+pub fn part_one_pooled(input: &str) -> Result<Answer, Error> {
+    let ranges = parse_ranges(input)?;
+    let sum = scan::sum(
+        &ranges,
+        scan::Options::default(),
+        || Vec::with_capacity(16),
+        |buffer, id| {
+            let invalid = is_invalid_id(id, buffer);
+            buffer.clear();
+            invalid
+        },
+    );
+
+    Ok(sum.into())
 }
 
 fn is_invalid_id2(id: usize, buffer: &mut Vec<u8>) -> bool {
@@ -97,7 +128,7 @@ fn is_invalid_id2(id: usize, buffer: &mut Vec<u8>) -> bool {
     // simply scan over the whole thing
     for window in 1..=digits.len() / 2 {
         // we can skip some checks
-        if (digits.len() % window) != 0 {
+        if !digits.len().is_multiple_of(window) {
             continue;
         }
 
@@ -113,11 +144,11 @@ fn is_invalid_id2(id: usize, buffer: &mut Vec<u8>) -> bool {
     false
 }
 
-pub fn part_two_simple(input: &str) -> impl Display {
+pub fn part_two_simple(input: &str) -> Result<Answer, Error> {
     let mut sum = 0;
     let mut buffer = Vec::with_capacity(16);
 
-    for (start, end) in parse_ranges(input) {
+    for (start, end) in parse_ranges(input)? {
         for i in start..=end {
             if is_invalid_id2(i, &mut buffer) {
                 sum += i;
@@ -126,15 +157,15 @@ pub fn part_two_simple(input: &str) -> impl Display {
         }
     }
 
-    sum
+    Ok(sum.into())
 }
 
-pub fn part_two(input: &str) -> impl Display {
+pub fn part_two(input: &str) -> Result<Answer, Error> {
     let (tx, rx) = std::sync::mpsc::sync_channel(16);
 
-    // We could probably balance these (we're probably waiting on
-    // one thread much longer than the rest), but I can't be bothered.
-    for (start, end) in parse_ranges(input) {
+    // We're probably waiting on one thread much longer than the rest,
+    // `part_two_pooled` balances the work instead.
+    for (start, end) in parse_ranges(input)? {
         let tx = tx.clone();
         std::thread::spawn(move || {
             let mut buffer = Vec::with_capacity(16);
@@ -153,7 +184,108 @@ pub fn part_two(input: &str) -> impl Display {
 
     drop(tx);
 
-    rx.iter().sum::<usize>()
+    Ok(rx.iter().sum::<usize>().into())
+}
+
+/// How many digits `id` has.
+fn digit_count(id: usize) -> u32 {
+    id.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// The sum of the `digits` long IDs in `start..=end` made of a
+/// `block` digit long number repeated.
+///
+/// Those IDs are the block times a number like `1001001`, for three
+/// blocks of three digits, so the blocks that fit form a range and
+/// the sum comes from that range's length and ends.
+fn repeating_sum(start: usize, end: usize, digits: u32, block: u32) -> u128 {
+    let low = (start as u128).max(10u128.pow(digits - 1));
+    let high = (end as u128).min(10u128.pow(digits) - 1);
+    if low > high {
+        return 0;
+    }
+
+    // any block times this has the full number of digits, so the
+    // clamping above keeps the blocks `block` digits long
+    let multiplier = (10u128.pow(digits) - 1) / (10u128.pow(block) - 1);
+    let first = low.div_ceil(multiplier);
+    let last = high / multiplier;
+    if first > last {
+        return 0;
+    }
+
+    multiplier * ((first + last) * (last - first + 1) / 2)
+}
+
+/// The Möbius function: 0 if `n` has a square factor, otherwise 1 or
+/// -1 for an even or odd number of prime factors.
+fn mobius(mut n: u32) -> i128 {
+    let mut sign = 1;
+    let mut factor = 2;
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            n /= factor;
+            if n.is_multiple_of(factor) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        factor += 1;
+    }
+
+    if n > 1 { -sign } else { sign }
+}
+
+pub fn part_one_arithmetic(input: &str) -> Result<Answer, Error> {
+    let mut sum = 0;
+
+    for (start, end) in parse_ranges(input)? {
+        for digits in digit_count(start)..=digit_count(end) {
+            if digits.is_multiple_of(2) {
+                sum += repeating_sum(start, end, digits, digits / 2);
+            }
+        }
+    }
+
+    Ok(sum.into())
+}
+
+pub fn part_two_arithmetic(input: &str) -> Result<Answer, Error> {
+    let mut sum = 0;
+
+    for (start, end) in parse_ranges(input)? {
+        for digits in digit_count(start)..=digit_count(end) {
+            // An ID made of one block repeated is also made of any
+            // shorter block that divides it, so adding up every block
+            // length counts some IDs more than once. Weighting each
+            // number of repeats by the Möbius function is the
+            // inclusion-exclusion that counts every ID once.
+            for repeats in 2..=digits {
+                if digits.is_multiple_of(repeats) {
+                    let block = digits / repeats;
+                    sum -= mobius(repeats) * repeating_sum(start, end, digits, block) as i128;
+                }
+            }
+        }
+    }
+
+    Ok(sum.into())
+}
+
+pub fn part_two_pooled(input: &str) -> Result<Answer, Error> {
+    let ranges = parse_ranges(input)?;
+    let sum = scan::sum(
+        &ranges,
+        scan::Options::default(),
+        || Vec::with_capacity(16),
+        |buffer, id| {
+            let invalid = is_invalid_id2(id, buffer);
+            buffer.clear();
+            invalid
+        },
+    );
+
+    Ok(sum.into())
 }
 
 #[cfg(test)]
@@ -191,4 +323,35 @@ mod test {
         buffer.clear();
         assert!(!is_invalid_id2(1112, &mut buffer));
     }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(repeating_sum(11, 22, 2, 1), 33);
+        assert_eq!(repeating_sum(95, 115, 3, 1), 111);
+        assert_eq!(
+            repeating_sum(1, 1_000_000, 6, 3),
+            (100..1000).sum::<u128>() * 1001
+        );
+        assert_eq!(mobius(6), 1);
+        assert_eq!(mobius(4), 0);
+        assert_eq!(mobius(7), -1);
+
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+            824824821-824824827,2121212118-2121212124";
+        assert_eq!(part_one_arithmetic(input).unwrap(), 1227775554);
+        assert_eq!(part_two_arithmetic(input).unwrap(), 4174379265u64);
+        assert_eq!(part_one_pooled(input).unwrap(), 1227775554);
+        assert_eq!(part_two_pooled(input).unwrap(), 4174379265u64);
+
+        let input = "1-30000,99990-100011,123123-123200";
+        assert_eq!(
+            part_one_arithmetic(input).unwrap(),
+            part_one_simple(input).unwrap()
+        );
+        assert_eq!(
+            part_two_arithmetic(input).unwrap(),
+            part_two_simple(input).unwrap()
+        );
+    }
 }
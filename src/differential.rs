@@ -0,0 +1,397 @@
+use crate::{
+    Answer,
+    days::{self, Part},
+    runner::{self, Options, Outcome},
+};
+use std::{fmt::Display, ops::RangeInclusive, sync::Arc, time::Duration};
+
+/// Every day's part that has more than one variant to compare.
+pub fn compared() -> Vec<(usize, Part)> {
+    let mut parts = Vec::new();
+    for solution in days::registry() {
+        let key = (solution.day(), solution.part());
+        if !parts.contains(&key) && days::variants(key.0, key.1).nth(1).is_some() {
+            parts.push(key);
+        }
+    }
+    parts
+}
+
+/// Variants of a part that gave different results for the same input.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub day: usize,
+    pub part: Part,
+    pub input: String,
+    /// Each variant's answer, or the error it failed with.
+    pub results: Vec<(&'static str, Result<Answer, String>)>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "day {} part {} variants disagree:", self.day, self.part)?;
+        for (variant, result) in &self.results {
+            match result {
+                Ok(answer) => writeln!(f, "  {variant}: {answer}")?,
+                Err(e) => writeln!(f, "  {variant}: error: {e}")?,
+            }
+        }
+        writeln!(f, "on this input:")?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Run every variant of a part on `input` and compare their answers.
+///
+/// Variants agree if they give the same answer, or if they all fail
+/// (the errors themselves may differ). A variant that takes longer than
+/// `timeout` is left out, so slow variants don't hold up real inputs.
+pub fn compare(day: usize, part: Part, input: &str, timeout: Duration) -> Option<Disagreement> {
+    let shared: Arc<str> = input.into();
+    let options = Options {
+        warmup: 0,
+        repetitions: 1,
+        timeout: Some(timeout),
+    };
+
+    let results: Vec<_> = days::variants(day, part)
+        .filter_map(|solution| {
+            let result = match runner::measure_with_timeout(solution, shared.clone(), &options) {
+                Outcome::Solved(measurement) => Ok(measurement.answer),
+                Outcome::Failed(e) => Err(e.to_string()),
+                Outcome::TimedOut(_) | Outcome::MissingInput(_) => return None,
+            };
+            Some((solution.variant(), result))
+        })
+        .collect();
+
+    let agree = results
+        .windows(2)
+        .all(|pair| match (&pair[0].1, &pair[1].1) {
+            (Ok(a), Ok(b)) => a == b,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        });
+
+    (!agree).then(|| Disagreement {
+        day,
+        part,
+        input: input.to_string(),
+        results,
+    })
+}
+
+/// Like [`compare`], but shrinks the input of any disagreement
+/// down to a small reproducer.
+pub fn check(day: usize, part: Part, input: &str, timeout: Duration) -> Option<Disagreement> {
+    let found = compare(day, part, input, timeout)?;
+    let minimized = shrink(&found.input, |candidate| {
+        compare(day, part, candidate, timeout).is_some()
+    });
+
+    // the disagreement is deterministic, but don't lose it if it isn't
+    compare(day, part, &minimized, timeout).or(Some(found))
+}
+
+/// Compare a part's variants on `runs` generated inputs, returning
+/// the first disagreement found.
+///
+/// Returns `None` without running anything if the day has no generator.
+pub fn fuzz(day: usize, part: Part, seed: u64, runs: usize) -> Option<Disagreement> {
+    let generate = generator(day)?;
+    let mut rng = Rng::new(seed);
+
+    (0..runs).find_map(|_| check(day, part, &generate(&mut rng), Duration::from_secs(10)))
+}
+
+/// Remove as much of `input` as possible while `fails` still holds.
+///
+/// Whole lines are removed, or the comma separated items of a
+/// single line, starting with large chunks and narrowing down.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let (body, newline) = match input.strip_suffix('\n') {
+        Some(body) => (body, "\n"),
+        None => (input, ""),
+    };
+    let separator = match body.contains('\n') {
+        false if body.contains(',') => ",",
+        _ => "\n",
+    };
+    let build = |units: &[&str]| format!("{}{newline}", units.join(separator));
+
+    let mut units: Vec<&str> = body.split(separator).collect();
+    let mut chunk = (units.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < units.len() && units.len() > 1 {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && fails(&build(&candidate)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if !removed {
+            if chunk == 1 {
+                break;
+            }
+            chunk /= 2;
+        }
+    }
+
+    build(&units)
+}
+
+/// A small, seedable xorshift generator, so generated inputs can be
+/// reproduced without pulling in a dependency.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // the state must never be zero
+        Self(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let span = (range.end() - range.start()) as u64 + 1;
+        range.start() + (self.next_u64() % span) as usize
+    }
+
+    /// True with the given percentage chance.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+}
+
+/// A generator of random inputs shaped like a day's puzzle input,
+/// for the days with variants to compare.
+pub fn generator(day: usize) -> Option<fn(&mut Rng) -> String> {
+    Some(match day {
+        2 => id_ranges,
+        4 => paper_rolls,
+        6 => worksheet,
+        7 => manifold,
+        8 => junction_boxes,
+        11 => devices,
+        _ => return None,
+    })
+}
+
+fn id_ranges(rng: &mut Rng) -> String {
+    let ranges: Vec<_> = (0..rng.range(1..=4))
+        .map(|_| {
+            let digits = rng.range(1..=6) as u32;
+            let start = rng.range(1..=10usize.pow(digits));
+            let end = start + rng.range(0..=2000);
+            format!("{start}-{end}")
+        })
+        .collect();
+    ranges.join(",")
+}
+
+fn paper_rolls(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(1..=12), rng.range(1..=12));
+    let mut grid = String::new();
+    for _ in 0..height {
+        grid.extend((0..width).map(|_| if rng.chance(60) { '@' } else { '.' }));
+        grid.push('\n');
+    }
+    grid
+}
+
+fn worksheet(rng: &mut Rng) -> String {
+    let rows = rng.range(2..=4);
+    let mut lines = vec![String::new(); rows + 1];
+
+    for problem in 0..rng.range(1..=6) {
+        let numbers: Vec<_> = (0..rows).map(|_| rng.range(1..=9999).to_string()).collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let right_aligned = rng.chance(50);
+
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            match right_aligned {
+                true => line.push_str(&format!("{number:>width$}")),
+                false => line.push_str(&format!("{number:<width$}")),
+            }
+        }
+        let operator = if rng.chance(50) { "+" } else { "*" };
+        lines[rows].push_str(&format!("{operator:<width$}"));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn manifold(rng: &mut Rng) -> String {
+    let width = 2 * rng.range(3..=10) + 1;
+    let start = width / 2;
+
+    let mut rows = vec![format!("{:.<start$}S{:.<start$}", "", "")];
+    for level in 1..=rng.range(1..=8) {
+        rows.push(".".repeat(width));
+        // splitters sit in a triangle below the start, on alternating columns
+        let row = (0..width)
+            .map(|x| {
+                let reachable =
+                    x.abs_diff(start) < level && (x + start + level - 1).is_multiple_of(2);
+                let inside = x > 0 && x < width - 1;
+                if reachable && inside && rng.chance(70) {
+                    '^'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        rows.push(row);
+    }
+    rows.push(".".repeat(width));
+
+    rows.join("\n") + "\n"
+}
+
+fn junction_boxes(rng: &mut Rng) -> String {
+    let mut boxes = String::new();
+    for _ in 0..rng.range(5..=40) {
+        let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+        boxes.push_str(&format!("{x},{y},{z}\n"));
+    }
+    boxes
+}
+
+fn devices(rng: &mut Rng) -> String {
+    let middle = rng.range(2..=10);
+    let mut names = vec!["svr".to_string()];
+    names.extend((0..middle).map(|i| format!("d{i:02}")));
+    names.push("out".to_string());
+
+    // the devices that must be visited go somewhere in the middle, in order
+    let first = rng.range(1..=middle - 1);
+    let second = rng.range(first + 1..=middle);
+    let (fft, dac) = match rng.chance(50) {
+        true => (first, second),
+        false => (second, first),
+    };
+    names[fft] = "fft".to_string();
+    names[dac] = "dac".to_string();
+
+    // connections only lead further along, so there are no cycles
+    let last = names.len() - 1;
+    let mut lines = Vec::new();
+    for from in 0..last {
+        let mut outputs: Vec<usize> = (0..rng.range(1..=3))
+            .map(|_| rng.range(from + 1..=last))
+            .collect();
+        for (a, b) in [(0, first), (first, second), (second, last)] {
+            if from == a {
+                outputs.push(b);
+            }
+        }
+        outputs.sort_unstable();
+        outputs.dedup();
+
+        let outputs: Vec<_> = outputs.iter().map(|i| names[*i].as_str()).collect();
+        lines.push(format!("{}: {}", names[from], outputs.join(" ")));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Error, input};
+    use std::path::Path;
+
+    /// Files in `dir` that look like examples for `day`, but might not
+    /// be named the way [`input::find`] looks for them.
+    fn example_shaped(dir: &Path, day: usize) -> Vec<String> {
+        let name = days::name(day).unwrap();
+        let mut found: Vec<_> = std::fs::read_dir(dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|file| {
+                let stem = file.split('.').next().unwrap_or_default().to_lowercase();
+                let example = stem.contains("test") || stem.contains("example");
+                example && (stem.starts_with(name) || stem == "test" || stem == "example")
+            })
+            .collect();
+        found.sort();
+        found
+    }
+
+    fn check_inputs(example: bool) {
+        let dir = Path::new(input::DEFAULT_DIR);
+        for (day, part) in compared() {
+            let path = match input::find(dir, day, example.then_some(part)) {
+                Ok(path) => path,
+                // a misnamed example would otherwise go unchecked
+                Err(Error::MissingInput { searched, .. }) if example => {
+                    let shaped = example_shaped(dir, day);
+                    assert!(
+                        shaped.is_empty(),
+                        "day {day}'s example wasn't found at {searched:?}, but {shaped:?} look like examples"
+                    );
+                    continue;
+                }
+                Err(_) => continue,
+            };
+            let input = input::read(&path).unwrap();
+            if let Some(disagreement) = check(day, part, &input, Duration::from_secs(10)) {
+                panic!("{disagreement}");
+            }
+        }
+    }
+
+    #[test]
+    fn examples() {
+        check_inputs(true);
+    }
+
+    #[test]
+    fn real_inputs() {
+        check_inputs(false);
+    }
+
+    #[test]
+    fn generated() {
+        for (day, part) in compared() {
+            let generate = generator(day).expect("every compared day has a generator");
+
+            // variants that all reject an input agree, so make sure they don't
+            let mut rng = Rng::new(day as u64);
+            for _ in 0..10 {
+                let input = generate(&mut rng);
+                let solution = days::find(day, part, None).unwrap();
+                if let Err(e) = solution.solve(&input) {
+                    panic!("day {day} part {part} rejected a generated input: {e}\n{input}");
+                }
+            }
+
+            if let Some(disagreement) = fuzz(day, part, 2025, 50) {
+                panic!("{disagreement}");
+            }
+        }
+    }
+
+    #[test]
+    fn shrunk() {
+        let fails = |input: &str| input.contains('c') && input.contains('f');
+        assert_eq!(shrink("a\nb\nc\nd\ne\nf\ng\n", fails), "c\nf\n");
+        assert_eq!(shrink("a-b,c-d,e-f", fails), "c-d,e-f");
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    days::{LAST_DAY, Part},
+    parse, readme,
+};
+use std::{fmt::Display, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input didn't have the shape a day expected.
+    Parse {
+        line: usize,
+        column: usize,
+        /// The offending text.
+        text: String,
+        expected: String,
+    },
+    /// The input parsed, but the solver couldn't produce an answer.
+    Failed(String),
+    /// No solver is registered for the requested day, part or variant.
+    Unsolved {
+        day: usize,
+        part: Part,
+        variant: Option<String>,
+    },
+    /// None of the searched paths held the day's input.
+    MissingInput { day: usize, searched: Vec<PathBuf> },
+    /// A line in the answers file couldn't be understood.
+    InvalidAnswers { line: usize, text: String },
+    /// A line in the submissions file couldn't be understood. The path
+    /// is known when the file was loaded, rather than parsed from text.
+    InvalidSubmissions {
+        path: Option<PathBuf>,
+        line: usize,
+        text: String,
+    },
+    /// No session token was set, or found in any of these files.
+    MissingSession { searched: Vec<PathBuf> },
+    /// A request to the puzzle server failed.
+    Http { url: String, reason: String },
+    /// The command can't be carried out as asked.
+    Usage(String),
+    /// An answer wasn't submitted, for this reason.
+    Refused(String),
+    /// The README has nowhere to put the timing table.
+    MissingMarkers { path: PathBuf },
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error {
+    /// Create a parse error for `text`, a slice somewhere inside `input`.
+    ///
+    /// The line and column (both starting from 1) are found from
+    /// where `text` lies in `input`.
+    pub fn parse(input: &[u8], text: &[u8], expected: impl Into<String>) -> Self {
+        let offset = parse::offset(input, text);
+
+        let before = &input[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|c| *c == b'\n')
+            .map_or(0, |i| i + 1);
+
+        Self::Parse {
+            line: before.iter().filter(|c| **c == b'\n').count() + 1,
+            column: offset - line_start + 1,
+            text: String::from_utf8_lossy(text).into_owned(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                text,
+                expected,
+            } => {
+                write!(f, "Parse error at {line}:{column}: expected {expected}")?;
+                match text.is_empty() {
+                    true => write!(f, ", found nothing"),
+                    false => write!(f, ", found `{text}`"),
+                }
+            }
+            Self::Failed(reason) => write!(f, "Failed to solve: {reason}"),
+            Self::Unsolved { day: 0, .. } => write!(f, "`0` isn't a valid day, silly!"),
+            Self::Unsolved { day, .. } if *day > LAST_DAY => {
+                write!(f, "There aren't even `{day}` days this year, silly!")
+            }
+            Self::Unsolved {
+                day,
+                part,
+                variant: Some(variant),
+            } => write!(f, "Day {day} part {part} has no `{variant}` variant"),
+            Self::Unsolved { day, part, .. } => {
+                write!(f, "Day {day} part {part} does not yet have a solution :/")
+            }
+            Self::MissingInput { day, searched } => {
+                write!(f, "No input found for day {day}. Searched:")?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::InvalidAnswers { line, text } => {
+                write!(f, "Invalid answer on line {line}: `{text}`")
+            }
+            Self::InvalidSubmissions { path, line, text } => match path {
+                Some(path) => write!(
+                    f,
+                    "Invalid submission on line {line} of {}: `{text}`",
+                    path.display()
+                ),
+                None => write!(f, "Invalid submission on line {line}: `{text}`"),
+            },
+            Self::MissingSession { searched } => {
+                write!(
+                    f,
+                    "No session token found. Set `AOC_SESSION` or save it in one of:"
+                )?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Http { url, reason } => write!(f, "Request to `{url}` failed: {reason}"),
+            Self::Usage(reason) => write!(f, "{reason}"),
+            Self::Refused(reason) => write!(f, "Not submitting: {reason}"),
+            Self::MissingMarkers { path } => write!(
+                f,
+                "`{}` needs `{}` and `{}` around the timing table",
+                path.display(),
+                readme::START,
+                readme::END
+            ),
+            Self::Io { path, source } => write!(f, "Failed to access `{path:?}`: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_position() {
+        let input = b"12\n3x4\n";
+        let Error::Parse {
+            line, column, text, ..
+        } = Error::parse(input, &input[4..5], "a digit")
+        else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!((line, column), (2, 2));
+        assert_eq!(text, "x");
+
+        let error = Error::parse(input, &input[7..], "a digit");
+        assert_eq!(
+            error.to_string(),
+            "Parse error at 3:1: expected a digit, found nothing"
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{Error, parse};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangle of cells, stored row by row.
+///
+/// Positions are `(x, y)`, with `x` counting columns from the left
+/// and `y` counting rows from the top, both from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parse rows of text with one cell per byte, skipping blank lines.
+    ///
+    /// `cell` turns a byte into a cell, or `None` if the byte isn't
+    /// allowed, in which case the error says it `expected` something
+    /// else. Every row has to be as wide as the first.
+    pub fn parse(
+        source: &[u8],
+        mut cell: impl FnMut(u8) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, Error> {
+        let mut cells = Vec::with_capacity(source.len());
+        let mut width = None;
+
+        for line in parse::lines(source).filter(|line| !line.is_empty()) {
+            match width {
+                None => width = Some(line.bytes.len()),
+                Some(width) if width != line.bytes.len() => {
+                    return Err(line.error(line.bytes, format!("a row {width} cells wide")));
+                }
+                _ => {}
+            }
+
+            for (i, byte) in line.bytes.iter().enumerate() {
+                let cell = cell(*byte).ok_or_else(|| line.error(&line.bytes[i..=i], expected))?;
+                cells.push(cell);
+            }
+        }
+
+        let width = width.ok_or_else(|| Error::parse(source, source, "a grid"))?;
+        Ok(Self {
+            height: cells.len() / width,
+            cells,
+            width,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every cell, row after row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Where `(x, y)` is in [`Grid::cells`], or `None` off the grid.
+    pub fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    /// The position of the cell at `index` in [`Grid::cells`].
+    pub fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    /// The cell at `(x, y)`, or `None` off the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.cells.get(self.index(x, y)?)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        let index = self.index(x, y)?;
+        self.cells.get_mut(index)
+    }
+
+    /// The positions up, left, right and down from `(x, y)`, leaving
+    /// out any off the grid.
+    ///
+    /// This doesn't borrow the grid, so it can be changed while
+    /// going through them.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.around(x, y, &ORTHOGONAL)
+    }
+
+    /// Like [`Grid::neighbors4`], but the diagonals too.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.around(x, y, &SURROUNDING)
+    }
+
+    fn around(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx).filter(|x| *x < width)?;
+            let y = y.checked_add_signed(*dy).filter(|y| *y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// The rows, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells in column `x`, from the top, or nothing off the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    /// The grid flipped over its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// The `width` by `height` part of the grid with its top left
+    /// at `(x, y)`, or `None` if that doesn't fit on the grid.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<View<'_, T>> {
+        let fits = x.checked_add(width)? <= self.width && y.checked_add(height)? <= self.height;
+        fits.then_some(View {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+}
+
+/// Panics if `(x, y)` is off the grid, use [`Grid::get`] to check.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is off a {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[x + y * self.width]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is off a {}x{} grid",
+            self.width,
+            self.height
+        );
+        &mut self.cells[x + y * self.width]
+    }
+}
+
+/// Shows the cells as characters, a line per row.
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+/// A rectangle inside a [`Grid`], with its own positions starting
+/// from its top left.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)` in the view, or `None` outside it.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.grid.get(self.x + x, self.y + y)
+    }
+
+    /// The rows of the view, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let (x, width) = (self.x, self.width);
+        self.grid
+            .rows()
+            .skip(self.y)
+            .take(self.height)
+            .map(move |row| &row[x..x + width])
+    }
+
+    /// Copy the view out into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.rows().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// Panics if `(x, y)` is outside the view, use [`View::get`] to check.
+impl<T> Index<(usize, usize)> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside a {}x{} view",
+            self.width,
+            self.height
+        );
+        &self.grid[(self.x + x, self.y + y)]
+    }
+}
+
+impl<T: Copy + Into<char>> Display for View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+fn write_rows<'a, T: Copy + Into<char> + 'a>(
+    f: &mut std::fmt::Formatter<'_>,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> std::fmt::Result {
+    for row in rows {
+        for cell in row {
+            write!(f, "{}", (*cell).into())?;
+        }
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn letters(source: &str) -> Result<Grid<u8>, Error> {
+        Grid::parse(
+            source.as_bytes(),
+            |c| c.is_ascii_lowercase().then_some(c),
+            "a letter",
+        )
+    }
+
+    #[test]
+    fn parsed() {
+        let grid = letters("abc\ndef\n\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], b'f');
+        assert_eq!(grid.get(1, 0), Some(&b'b'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.index(1, 1), Some(4));
+        assert_eq!(grid.position(4), (1, 1));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        assert!(matches!(
+            letters("abc\nde\n"),
+            Err(Error::Parse { line: 2, column: 1, ref expected, .. })
+                if expected == "a row 3 cells wide"
+        ));
+        assert!(matches!(
+            letters("abc\r\ndEf\r\n"),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(letters("\n").is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 3, 0u8);
+
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(
+            grid.neighbors8(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(grid.neighbors4(2, 2).collect::<Vec<_>>(), [(2, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn reshaped() {
+        let mut grid = letters("abc\ndef\n").unwrap();
+        grid[(0, 0)] = b'z';
+
+        let rows: Vec<_> = grid.rows().collect();
+        assert_eq!(rows, [b"zbc", b"def"]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.transpose().to_string(), "zd\nbe\ncf\n");
+
+        let view = grid.view(1, 0, 2, 2).unwrap();
+        assert_eq!(view.to_string(), "bc\nef\n");
+        assert_eq!(view[(1, 1)], b'f');
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.to_grid().transpose().to_string(), "be\ncf\n");
+        assert!(grid.view(2, 0, 2, 1).is_none());
+    }
+
+    #[test]
+    #[should_panic = "(0, 2) is off a 3x2 grid"]
+    fn indexed_below() {
+        let grid = letters("abc\ndef\n").unwrap();
+        let _ = grid[(0, 2)];
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    Error,
+    days::{self, Part},
+};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// The directory inputs are searched for by default.
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Where an input was read from, and a hash of its contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    /// The input file, or `-` for stdin.
+    pub path: PathBuf,
+    pub hash: u64,
+}
+
+impl Source {
+    pub fn new(path: &Path, input: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            hash: hash(input),
+        }
+    }
+}
+
+/// A 64-bit FNV-1a hash of an input.
+///
+/// This is stable across builds and platforms, so it can be used to
+/// tell whether two runs saw the same input.
+pub fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The file name for a day's input, like `one.txt` or `one_test.txt`.
+pub fn file_name(day: usize, example: bool) -> Option<String> {
+    let name = days::name(day)?;
+    Some(match example {
+        true => format!("{name}_test.txt"),
+        false => format!("{name}.txt"),
+    })
+}
+
+/// The paths searched for a day's input, in order.
+///
+/// `example` asks for the example used by a part instead. A part with
+/// an example of its own has it in a file like `eleven_test2.txt`,
+/// otherwise the day's example, like `eleven_test.txt`, is used.
+pub fn search_paths(dir: &Path, day: usize, example: Option<Part>) -> Vec<PathBuf> {
+    let Some(name) = days::name(day) else {
+        return Vec::new();
+    };
+
+    match example {
+        Some(part) => vec![
+            dir.join(format!("{name}_test{part}.txt")),
+            dir.join(format!("{name}_test.txt")),
+        ],
+        None => vec![dir.join(format!("{name}.txt"))],
+    }
+}
+
+/// Find the first existing input for a day, or for a part's example.
+pub fn find(dir: &Path, day: usize, example: Option<Part>) -> Result<PathBuf, Error> {
+    let searched = search_paths(dir, day, example);
+
+    match searched.iter().find(|p| p.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(Error::MissingInput { day, searched }),
+    }
+}
+
+/// Read an input file, where `-` reads from stdin.
+pub fn read(path: &Path) -> Result<String, Error> {
+    let io_error = |source| Error::Io {
+        path: path.to_owned(),
+        source,
+    };
+
+    if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(io_error)?;
+        return Ok(input);
+    }
+
+    std::fs::read_to_string(path).map_err(io_error)
+}
+
+/// Read a day's input from `path` if given, otherwise search `dir` for it.
+pub fn load(
+    path: Option<&Path>,
+    dir: &Path,
+    day: usize,
+    example: Option<Part>,
+) -> Result<(String, Source), Error> {
+    let path = match path {
+        Some(path) => path.to_owned(),
+        None => find(dir, day, example)?,
+    };
+
+    let input = read(&path)?;
+    let source = Source::new(&path, &input);
+    Ok((input, source))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(file_name(1, false).unwrap(), "one.txt");
+        assert_eq!(file_name(8, true).unwrap(), "eight_test.txt");
+        assert!(file_name(0, false).is_none());
+        assert!(file_name(13, false).is_none());
+    }
+
+    #[test]
+    fn search() {
+        let paths = search_paths(Path::new("inputs"), 7, Some(Part::One));
+        assert_eq!(paths[0], Path::new("inputs/seven_test1.txt"));
+        assert_eq!(paths[1], Path::new("inputs/seven_test.txt"));
+
+        let path = find(Path::new("inputs"), 7, Some(Part::Two)).unwrap();
+        assert!(read(&path).unwrap().starts_with(".......S"));
+
+        let path = find(Path::new("inputs"), 11, Some(Part::Two)).unwrap();
+        assert_eq!(path, Path::new("inputs/eleven_test2.txt"));
+        let path = find(Path::new("inputs"), 11, Some(Part::One)).unwrap();
+        assert_eq!(path, Path::new("inputs/eleven_test.txt"));
+
+        let missing = find(Path::new("nowhere"), 7, None);
+        assert!(matches!(
+            missing,
+            Err(Error::MissingInput { day: 7, searched }) if searched == [Path::new("nowhere/seven.txt")]
+        ));
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(hash("12"), hash("21"));
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::{Display, Write};
+
+/// A JSON value, with just enough structure for reporting results.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// A number, already formatted.
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    /// Fields are written in the order they were given.
+    Object(Vec<(&'static str, Value)>),
+}
+
+impl Value {
+    /// Create an object from its fields.
+    pub fn object(fields: impl IntoIterator<Item = (&'static str, Value)>) -> Self {
+        Self::Object(fields.into_iter().collect())
+    }
+}
+
+macro_rules! from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Self::Number(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+from_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+/// Write `text` as a quoted JSON string.
+fn write_string(f: &mut std::fmt::Formatter<'_>, text: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for char in text.chars() {
+        match char {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Number(value) => f.write_str(value),
+            Self::String(value) => write_string(f, value),
+            Self::Array(values) => {
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Self::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn written() {
+        let value = Value::object([
+            ("day", 3.into()),
+            ("answer", "a \"quoted\"\n\u{1}".into()),
+            ("hash", None::<u64>.into()),
+            ("list", Value::Array(vec![true.into(), (-4i64).into()])),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"day":3,"answer":"a \"quoted\"\n\u0001","hash":null,"list":[true,-4]}"#
+        );
+    }
+}
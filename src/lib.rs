@@ -1 +1,42 @@
+pub mod answer;
+pub mod answers;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod days;
+#[cfg(test)]
+mod differential;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod readme;
+pub mod runner;
+pub mod scaffold;
+pub mod scan;
+pub mod submissions;
+pub mod watch;
+
+pub use answer::Answer;
+pub use days::Part;
+pub use error::Error;
+
+/// Solve one part of a day with its preferred variant.
+///
+/// ```
+/// use aoc_2025::{Answer, Part};
+///
+/// let answer = aoc_2025::run(11, Part::One, "you: aaa out\naaa: out\n")?;
+/// assert_eq!(answer, 2);
+/// # Ok::<(), aoc_2025::Error>(())
+/// ```
+pub fn run(day: usize, part: Part, input: &str) -> Result<Answer, Error> {
+    days::find(day, part, None)?.solve(input)
+}
+
+/// Solve one part of a day with a specific variant, as listed by [`days::variants`].
+pub fn run_variant(day: usize, part: Part, variant: &str, input: &str) -> Result<Answer, Error> {
+    days::find(day, part, Some(variant))?.solve(input)
+}
@@ -0,0 +1,327 @@
+use crate::Error;
+
+/// An integer that can be read from ASCII digits.
+pub trait Integer: Copy {
+    /// Whether the type has negative values.
+    const SIGNED: bool;
+    const BITS: u32;
+    const ZERO: Self;
+
+    /// Shift in another digit, returning `None` on overflow.
+    /// Negative numbers subtract their digits instead.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = <$t>::MIN != 0;
+                const BITS: u32 = <$t>::BITS;
+                const ZERO: Self = 0;
+
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(10)?;
+                    match negative {
+                        true => shifted.checked_sub(digit as Self),
+                        false => shifted.checked_add(digit as Self),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Where `text` starts within `source`, or 0 if it isn't part of it.
+pub(crate) fn offset(source: &[u8], text: &[u8]) -> usize {
+    (text.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|offset| *offset <= source.len())
+        .unwrap_or_default()
+}
+
+/// Parse an integer spanning all of `bytes`, which is part of `source`.
+///
+/// Signed types take a leading `-`. Errors point into `source`,
+/// so they give the line and column of the problem.
+pub fn integer<T: Integer>(source: &[u8], bytes: &[u8]) -> Result<T, Error> {
+    let (negative, digits) = match bytes.split_first() {
+        Some((b'-', rest)) if T::SIGNED => (true, rest),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return Err(Error::parse(source, bytes, "a number"));
+    }
+
+    let mut value = T::ZERO;
+    for (i, byte) in digits.iter().enumerate() {
+        if !byte.is_ascii_digit() {
+            return Err(Error::parse(source, &digits[i..=i], "a digit"));
+        }
+
+        value = value.push_digit(byte - b'0', negative).ok_or_else(|| {
+            Error::parse(
+                source,
+                bytes,
+                format!("a number that fits in {} bits", T::BITS),
+            )
+        })?;
+    }
+
+    Ok(value)
+}
+
+/// Read digits that aren't next to each other in the input,
+/// like a column of them, as one number.
+///
+/// Returns `None` if anything isn't a digit or the number overflows.
+pub fn digits<T: Integer>(digits: impl IntoIterator<Item = u8>) -> Option<T> {
+    digits.into_iter().try_fold(T::ZERO, |value, byte| {
+        byte.is_ascii_digit().then_some(())?;
+        value.push_digit(byte - b'0', false)
+    })
+}
+
+/// Parse integers separated by `delimiter`, like `1,2,3`.
+pub fn list<T: Integer>(source: &[u8], bytes: &[u8], delimiter: u8) -> Result<Vec<T>, Error> {
+    bytes
+        .split(|b| *b == delimiter)
+        .map(|item| integer(source, item))
+        .collect()
+}
+
+/// Split on runs of spaces, ignoring any at either end.
+pub fn words(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    bytes.split(|b| *b == b' ').filter(|word| !word.is_empty())
+}
+
+/// Parse integers separated by any number of spaces, like ` 12  3 45`.
+pub fn spaced<T: Integer>(source: &[u8], bytes: &[u8]) -> Result<Vec<T>, Error> {
+    words(bytes).map(|word| integer(source, word)).collect()
+}
+
+/// Parse a range like `3-5` into its bounds.
+pub fn range<T: Integer>(source: &[u8], bytes: &[u8]) -> Result<(T, T), Error> {
+    // a negative start isn't the dash
+    let Some(dash) = bytes.iter().skip(1).position(|b| *b == b'-').map(|i| i + 1) else {
+        return Err(Error::parse(source, bytes, "a range like `3-5`"));
+    };
+
+    let (start, end) = (&bytes[..dash], &bytes[dash + 1..]);
+    Ok((integer(source, start)?, integer(source, end)?))
+}
+
+/// Parse exactly `N` integers separated by `delimiter`,
+/// like the coordinate `162,817,812`.
+pub fn tuple<T: Integer, const N: usize>(
+    source: &[u8],
+    bytes: &[u8],
+    delimiter: u8,
+) -> Result<[T; N], Error> {
+    let wrong_count = || {
+        let expected = format!("{N} numbers separated by `{}`", delimiter as char);
+        Error::parse(source, bytes, expected)
+    };
+
+    let mut items = bytes.split(|b| *b == delimiter);
+    let mut tuple = [T::ZERO; N];
+    for value in &mut tuple {
+        *value = integer(source, items.next().ok_or_else(wrong_count)?)?;
+    }
+
+    match items.next() {
+        Some(_) => Err(wrong_count()),
+        None => Ok(tuple),
+    }
+}
+
+/// Split `source` on blank lines, which may end in `\r\n`.
+pub fn sections(source: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = Some(source);
+    std::iter::from_fn(move || {
+        let current = rest?;
+        // a line break, then a line with nothing or only `\r` on it
+        let blank = (0..current.len()).find_map(|i| {
+            let after = current[i..].strip_prefix(b"\n")?;
+            let after = after.strip_prefix(b"\r").unwrap_or(after);
+            after
+                .starts_with(b"\n")
+                .then(|| (i, current.len() - after.len() + 1))
+        });
+
+        match blank {
+            Some((end, next)) => {
+                rest = Some(&current[next..]);
+                Some(
+                    current[..end]
+                        .strip_suffix(b"\r")
+                        .unwrap_or(&current[..end]),
+                )
+            }
+            None => {
+                rest = None;
+                Some(current)
+            }
+        }
+    })
+}
+
+/// A line of the input, which knows where it is in the input
+/// so the parsers on it can report positions.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// Counted from 1, like editors do.
+    pub number: usize,
+    pub bytes: &'a [u8],
+    source: &'a [u8],
+}
+
+impl<'a> Line<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The whole input the line is part of, for parsing parts of
+    /// the line with the functions here.
+    pub fn source(&self) -> &'a [u8] {
+        self.source
+    }
+
+    /// An error about `text`, some part of this line.
+    pub fn error(&self, text: &[u8], expected: impl Into<String>) -> Error {
+        Error::parse(self.source, text, expected)
+    }
+
+    pub fn integer<T: Integer>(&self) -> Result<T, Error> {
+        integer(self.source, self.bytes)
+    }
+
+    pub fn list<T: Integer>(&self, delimiter: u8) -> Result<Vec<T>, Error> {
+        list(self.source, self.bytes, delimiter)
+    }
+
+    pub fn spaced<T: Integer>(&self) -> Result<Vec<T>, Error> {
+        spaced(self.source, self.bytes)
+    }
+
+    pub fn range<T: Integer>(&self) -> Result<(T, T), Error> {
+        range(self.source, self.bytes)
+    }
+
+    pub fn tuple<T: Integer, const N: usize>(&self, delimiter: u8) -> Result<[T; N], Error> {
+        tuple(self.source, self.bytes, delimiter)
+    }
+}
+
+/// The lines of `source`.
+///
+/// Like [`str::lines`], a trailing newline doesn't add an empty
+/// line, and carriage returns before newlines are dropped.
+pub fn lines(source: &[u8]) -> impl Iterator<Item = Line<'_>> {
+    lines_in(source, source)
+}
+
+/// The lines of `bytes`, some part of `source` like one of its
+/// [`sections`], numbered by where they are in `source`.
+pub fn lines_in<'a>(source: &'a [u8], bytes: &'a [u8]) -> impl Iterator<Item = Line<'a>> {
+    let before = &source[..offset(source, bytes)];
+    let first = before.iter().filter(|b| **b == b'\n').count() + 1;
+
+    let count = match bytes.is_empty() {
+        true => 0,
+        false => usize::MAX,
+    };
+    let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+
+    bytes
+        .split(|b| *b == b'\n')
+        .take(count)
+        .enumerate()
+        .map(move |(i, line)| Line {
+            number: first + i,
+            bytes: line.strip_suffix(b"\r").unwrap_or(line),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let source = b"123 -45 99999999999 +1 -";
+        let words: Vec<_> = words(source).collect();
+
+        assert_eq!(integer::<u32>(source, words[0]).unwrap(), 123);
+        assert_eq!(integer::<i64>(source, words[1]).unwrap(), -45);
+        assert_eq!(integer::<i8>(b"-128", b"-128").unwrap(), i8::MIN);
+        assert!(matches!(
+            integer::<u32>(source, words[1]),
+            Err(Error::Parse { column: 5, .. })
+        ));
+        assert!(matches!(
+            integer::<u32>(source, words[2]),
+            Err(Error::Parse { column: 9, ref expected, .. }) if expected.contains("32 bits")
+        ));
+        assert_eq!(integer::<u64>(source, words[2]).unwrap(), 99999999999);
+        assert!(integer::<i32>(source, words[3]).is_err());
+        assert!(matches!(
+            integer::<i32>(source, words[4]),
+            Err(Error::Parse { ref expected, .. }) if expected == "a number"
+        ));
+
+        assert_eq!(digits::<u8>(*b"255"), Some(255));
+        assert_eq!(digits::<u8>(*b"256"), None);
+        assert_eq!(digits::<u8>(*b"2 5"), None);
+    }
+
+    #[test]
+    fn delimited() {
+        let source = b"1,2,3\n3-5\n-4--2\n 1  2 3 \n1,2";
+        let lines: Vec<_> = lines(source).collect();
+
+        assert_eq!(lines[0].list::<u8>(b',').unwrap(), [1, 2, 3]);
+        assert_eq!(lines[0].tuple::<i64, 3>(b',').unwrap(), [1, 2, 3]);
+        assert_eq!(lines[1].range::<u64>().unwrap(), (3, 5));
+        assert_eq!(lines[2].range::<i32>().unwrap(), (-4, -2));
+        assert_eq!(lines[3].spaced::<u32>().unwrap(), [1, 2, 3]);
+        assert!(matches!(
+            lines[4].tuple::<i64, 3>(b','),
+            Err(Error::Parse { line: 5, .. })
+        ));
+        assert!(lines[0].tuple::<i64, 2>(b',').is_err());
+        assert!(lines[1].list::<u8>(b',').is_err());
+    }
+
+    #[test]
+    fn positioned() {
+        let source = b"a\r\nb\n\nc\nd\n\n\ne\n";
+
+        let numbered: Vec<_> = lines(source).map(|l| (l.number, l.bytes)).collect();
+        assert_eq!(numbered[0], (1, &b"a"[..]));
+        assert_eq!(numbered[2], (3, &b""[..]));
+        assert_eq!(numbered.len(), 8);
+        assert_eq!(lines(b"").count(), 0);
+        assert_eq!(lines(b"\n").count(), 1);
+
+        let sections: Vec<_> = sections(source).collect();
+        assert_eq!(sections.len(), 3);
+        let numbered: Vec<_> = lines_in(source, sections[1])
+            .map(|l| (l.number, l.bytes))
+            .collect();
+        assert_eq!(numbered, [(4, &b"c"[..]), (5, &b"d"[..])]);
+
+        let source = b"a\r\nb\r\n\r\nc\r\n\r\n\r\nd\r\n";
+        let crlf: Vec<_> = super::sections(source).collect();
+        assert_eq!(crlf, [&b"a\r\nb"[..], b"c", b"\r\nd\r\n"]);
+        let numbered: Vec<_> = lines_in(source, crlf[1])
+            .map(|l| (l.number, l.bytes))
+            .collect();
+        assert_eq!(numbered, [(4, &b"c"[..])]);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    Error,
+    days::Part,
+    runner::{Outcome, Report, format_duration},
+};
+use std::{collections::HashMap, path::Path};
+
+/// Marks the start of the generated timing table.
+pub const START: &str = "<!-- timings:start -->";
+/// Marks the end of the generated timing table.
+pub const END: &str = "<!-- timings:end -->";
+
+/// A markdown table of timings, with one column per variant.
+///
+/// The `Time` column records how long a part took to solve by hand,
+/// so it's kept from `previous`, the table being replaced.
+pub fn table(reports: &[Report], previous: &str) -> String {
+    let times = solve_times(previous);
+
+    // variants are ordered by when they first appear
+    let mut variants: Vec<&str> = Vec::new();
+    for report in reports {
+        let variant = report.solution.variant();
+        if !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+
+    let mut rows: Vec<(usize, Part)> = reports
+        .iter()
+        .map(|r| (r.solution.day(), r.solution.part()))
+        .collect();
+    rows.dedup();
+
+    let mut header = vec!["Challenge".to_string(), "Time".to_string()];
+    header.extend(variants.iter().map(|v| v.to_string()));
+
+    let mut cells = vec![header];
+    for (day, part) in rows {
+        let label = label(day, part);
+        let mut row = vec![
+            label.clone(),
+            times.get(label.as_str()).cloned().unwrap_or_default(),
+        ];
+
+        for variant in &variants {
+            let report = reports.iter().find(|r| {
+                (r.solution.day(), r.solution.part(), r.solution.variant()) == (day, part, *variant)
+            });
+
+            row.push(match report.map(|r| &r.outcome) {
+                Some(Outcome::Solved(m)) => format_duration(m.median),
+                Some(Outcome::Failed(_)) => "error".into(),
+                Some(Outcome::TimedOut(_)) => "timeout".into(),
+                Some(Outcome::MissingInput(_)) => "no input".into(),
+                None => String::new(),
+            });
+        }
+
+        cells.push(row);
+    }
+
+    let mut widths = vec![3; cells[0].len()];
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    for (i, row) in cells.iter().enumerate() {
+        let line: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        table.push_str(&format!("| {} |\n", line.join(" | ")));
+
+        if i == 0 {
+            let line: Vec<_> = widths.iter().map(|w| "-".repeat(*w)).collect();
+            table.push_str(&format!("| {} |\n", line.join(" | ")));
+        }
+    }
+
+    table
+}
+
+fn label(day: usize, part: Part) -> String {
+    format!("Day {day} P{part}")
+}
+
+/// The `Time` cells of an existing table, keyed by challenge.
+fn solve_times(table: &str) -> HashMap<&str, String> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut cells = line.trim().strip_prefix('|')?.split('|').map(str::trim);
+            let challenge = cells.next()?;
+            let time = cells.next()?;
+            challenge
+                .starts_with("Day ")
+                .then(|| (challenge, time.to_string()))
+        })
+        .collect()
+}
+
+/// Replace the table between the markers in `readme`.
+pub fn splice(readme: &str, reports: &[Report]) -> Option<String> {
+    let start = readme.find(START)? + START.len();
+    let end = start + readme[start..].find(END)?;
+
+    let table = table(reports, &readme[start..end]);
+    Some(format!(
+        "{}\n\n{table}\n{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+/// Rewrite the timing table in the README at `path`.
+pub fn update(path: &Path, reports: &[Report]) -> Result<(), Error> {
+    let io_error = |source| Error::Io {
+        path: path.to_owned(),
+        source,
+    };
+
+    let readme = std::fs::read_to_string(path).map_err(io_error)?;
+    let updated = splice(&readme, reports).ok_or_else(|| Error::MissingMarkers {
+        path: path.to_owned(),
+    })?;
+
+    std::fs::write(path, updated).map_err(io_error)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        Answer,
+        days::{Solution, Solver},
+        runner::Measurement,
+    };
+    use std::time::Duration;
+
+    static ONE: Solver = Solver::new(1, Part::One, "default", |_| Ok(Answer::from(1)));
+    static TWO: Solver = Solver::new(1, Part::Two, "default", |_| Ok(Answer::from(2)));
+    static SLOW: Solver = Solver::new(1, Part::Two, "slow", |_| Ok(Answer::from(2)));
+
+    fn solved(solution: &'static dyn Solution, micros: u64) -> Report {
+        Report {
+            solution,
+            source: None,
+            outcome: Outcome::Solved(Measurement {
+                answer: solution.solve("").unwrap(),
+                median: Duration::from_micros(micros),
+            }),
+        }
+    }
+
+    #[test]
+    fn spliced() {
+        let readme = format!(
+            "# Title\n{START}\n| Challenge | Time |\n| Day 1 P2 | 5m | 2ms | 1ms |\n{END}\nAfter\n"
+        );
+        let reports = [solved(&ONE, 5), solved(&TWO, 2_000), solved(&SLOW, 21)];
+
+        let expected = format!(
+            "# Title\n{START}\n\n\
+            | Challenge | Time | default | slow   |\n\
+            | --------- | ---- | ------- | ------ |\n\
+            | Day 1 P1  |      | 5.0us   |        |\n\
+            | Day 1 P2  | 5m   | 2.00ms  | 21.0us |\n\
+            \n{END}\nAfter\n"
+        );
+        assert_eq!(splice(&readme, &reports).unwrap(), expected);
+
+        // regenerating keeps the same table
+        assert_eq!(splice(&expected, &reports).unwrap(), expected);
+        assert!(splice("# No markers", &reports).is_none());
+    }
+}
@@ -0,0 +1,476 @@
+use crate::{
+    Answer, Error,
+    answers::{Answers, Verdict},
+    days::{self, Solution},
+    input::{self, Source},
+    json::Value,
+};
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt::Display,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+    time::{Duration, Instant},
+};
+
+/// How many times each solver is run when measuring it.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Untimed runs before measuring, to warm up caches.
+    pub warmup: usize,
+    /// Timed runs. The median is reported.
+    pub repetitions: usize,
+    /// How long a single run may take before it's abandoned.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            repetitions: 5,
+            timeout: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub answer: Answer,
+    pub median: Duration,
+}
+
+/// Solve `input` several times, returning the answer and median time.
+pub fn measure(
+    solution: &dyn Solution,
+    input: &str,
+    options: &Options,
+) -> Result<Measurement, Error> {
+    for _ in 0..options.warmup {
+        solution.solve(input)?;
+    }
+
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(options.repetitions);
+    for _ in 0..options.repetitions.max(1) {
+        let start = Instant::now();
+        answer = Some(solution.solve(input)?);
+        samples.push(start.elapsed());
+    }
+    samples.sort_unstable();
+
+    Ok(Measurement {
+        answer: answer.expect("solved at least once"),
+        median: samples[samples.len() / 2],
+    })
+}
+
+/// Measure a solver on a worker thread, giving up on any run
+/// that takes longer than the timeout.
+///
+/// Threads can't be stopped from outside, so an abandoned
+/// run keeps going in the background until it finishes.
+pub fn measure_with_timeout(
+    solution: &'static dyn Solution,
+    input: Arc<str>,
+    options: &Options,
+) -> Outcome {
+    let Some(timeout) = options.timeout else {
+        return match measure(solution, &input, options) {
+            Ok(measurement) => Outcome::Solved(measurement),
+            Err(e) => Outcome::Failed(e),
+        };
+    };
+
+    let runs = options.warmup + options.repetitions.max(1);
+    let (sender, receiver) = mpsc::channel();
+    let worker = std::thread::Builder::new()
+        .name(format!("day {} part {}", solution.day(), solution.part()))
+        .spawn(move || {
+            for _ in 0..runs {
+                let start = Instant::now();
+                let result = solution
+                    .solve(&input)
+                    .map(|answer| (answer, start.elapsed()));
+                let failed = result.is_err();
+                if sender.send(result).is_err() || failed {
+                    break;
+                }
+            }
+        });
+    if let Err(e) = worker {
+        return Outcome::Failed(Error::Failed(format!("couldn't start a worker: {e}")));
+    }
+
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(options.repetitions);
+    for run in 0..runs {
+        match receiver.recv_timeout(timeout) {
+            Ok(Ok((solved, elapsed))) => {
+                if run >= options.warmup {
+                    samples.push(elapsed);
+                }
+                answer = Some(solved);
+            }
+            Ok(Err(e)) => return Outcome::Failed(e),
+            Err(mpsc::RecvTimeoutError::Timeout) => return Outcome::TimedOut(timeout),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Outcome::Failed(Error::Failed("the solver panicked".into()));
+            }
+        }
+    }
+    samples.sort_unstable();
+
+    Outcome::Solved(Measurement {
+        answer: answer.expect("solved at least once"),
+        median: samples[samples.len() / 2],
+    })
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Measurement),
+    Failed(Error),
+    /// A run took longer than this, so the solver was abandoned.
+    TimedOut(Duration),
+    /// No input was found at any of these paths.
+    MissingInput(Vec<PathBuf>),
+}
+
+/// The result of running a single solver.
+pub struct Report {
+    pub solution: &'static dyn Solution,
+    /// The input that was solved, if one was found.
+    pub source: Option<Source>,
+    pub outcome: Outcome,
+}
+
+impl Report {
+    /// This report as a JSON object, for scripts to consume.
+    ///
+    /// When `answers` are given, the answer is checked against them.
+    pub fn to_json(&self, answers: Option<&Answers>) -> Value {
+        let (day, part) = (self.solution.day(), self.solution.part());
+        let (answer, elapsed, error) = match &self.outcome {
+            Outcome::Solved(m) => (Some(&m.answer), Some(m.median.as_nanos()), None),
+            Outcome::Failed(e) => (None, None, Some(e.to_string())),
+            Outcome::TimedOut(timeout) => (
+                None,
+                None,
+                Some(format!("timed out after {}", format_duration(*timeout))),
+            ),
+            Outcome::MissingInput(_) => (None, None, Some("no input".to_string())),
+        };
+
+        let verdict = match &self.outcome {
+            Outcome::Solved(m) => answers.map(|answers| answers.check(day, part, &m.answer)),
+            _ => None,
+        };
+        let (verdict, expected) = match verdict {
+            Some(Verdict::Correct) => (Some("correct"), None),
+            Some(Verdict::Wrong { expected }) => (Some("wrong"), Some(expected)),
+            Some(Verdict::Unknown) => (Some("unknown"), None),
+            None => (None, None),
+        };
+
+        Value::object([
+            ("day", day.into()),
+            ("part", part.number().into()),
+            ("variant", self.solution.variant().into()),
+            ("answer", answer.into()),
+            ("elapsed_ns", elapsed.into()),
+            (
+                "input",
+                self.source
+                    .as_ref()
+                    .map(|s| s.path.display().to_string())
+                    .into(),
+            ),
+            (
+                "input_hash",
+                self.source
+                    .as_ref()
+                    .map(|s| format!("{:016x}", s.hash))
+                    .into(),
+            ),
+            ("verdict", verdict.into()),
+            ("expected", expected.as_ref().into()),
+            ("error", error.into()),
+        ])
+    }
+}
+
+/// Measure every registered solver against its input in `inputs`.
+///
+/// Unless `all_variants` is set, only the preferred variant
+/// of each part is run.
+pub fn run_all(inputs: &Path, example: bool, options: &Options, all_variants: bool) -> Vec<Report> {
+    let mut loaded = HashMap::new();
+    let mut reports: Vec<Report> = Vec::new();
+
+    for &solution in days::registry() {
+        let day = solution.day();
+        let is_preferred = !reports
+            .iter()
+            .any(|r| (r.solution.day(), r.solution.part()) == (day, solution.part()));
+        if !all_variants && !is_preferred {
+            continue;
+        }
+
+        let example = example.then_some(solution.part());
+        let input = match loaded.entry((day, example)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match input::load(None, inputs, day, example) {
+                Ok((input, source)) => entry.insert((Arc::<str>::from(input), source)),
+                Err(e) => {
+                    let outcome = match e {
+                        Error::MissingInput { searched, .. } => Outcome::MissingInput(searched),
+                        e => Outcome::Failed(e),
+                    };
+                    reports.push(Report {
+                        solution,
+                        source: None,
+                        outcome,
+                    });
+                    continue;
+                }
+            },
+        };
+
+        let outcome = measure_with_timeout(solution, input.0.clone(), options);
+
+        reports.push(Report {
+            solution,
+            source: Some(input.1.clone()),
+            outcome,
+        });
+    }
+
+    reports
+}
+
+/// Format a duration with a single unit, like `21.3us` or `6.70ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    match nanos {
+        n if n < 1e3 => format!("{n:.0}ns"),
+        n if n < 1e6 => format!("{:.1}us", n / 1e3),
+        n if n < 1e9 => format!("{:.2}ms", n / 1e6),
+        n => format!("{:.2}s", n / 1e9),
+    }
+}
+
+/// Whether any report disagrees with its accepted answer, or failed
+/// or timed out instead of giving one.
+///
+/// Days without an input aren't counted, as there's nothing to check.
+pub fn any_failing(reports: &[Report], answers: &Answers) -> bool {
+    reports.iter().any(|report| match &report.outcome {
+        Outcome::Solved(m) => matches!(
+            answers.check(report.solution.day(), report.solution.part(), &m.answer),
+            Verdict::Wrong { .. }
+        ),
+        Outcome::Failed(_) | Outcome::TimedOut(_) => true,
+        Outcome::MissingInput(_) => false,
+    })
+}
+
+/// An aligned table of reports, followed by the total time.
+pub struct Summary<'a> {
+    pub reports: &'a [Report],
+    /// When present, the status of each answer is checked against these.
+    pub answers: Option<&'a Answers>,
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const HEADER: [&str; 6] = ["Day", "Part", "Variant", "Answer", "Median", "Status"];
+
+        let rows: Vec<[String; 6]> = self
+            .reports
+            .iter()
+            .map(|report| {
+                let (answer, median, status) = match &report.outcome {
+                    Outcome::Solved(m) => {
+                        let status = match self.answers {
+                            Some(answers) => answers
+                                .check(report.solution.day(), report.solution.part(), &m.answer)
+                                .to_string(),
+                            None => "ok".into(),
+                        };
+                        (m.answer.to_string(), format_duration(m.median), status)
+                    }
+                    Outcome::Failed(e) => (String::new(), String::new(), format!("error: {e}")),
+                    Outcome::TimedOut(_) => (String::new(), String::new(), "TIMEOUT".into()),
+                    Outcome::MissingInput(_) => (String::new(), String::new(), "no input".into()),
+                };
+
+                [
+                    report.solution.day().to_string(),
+                    report.solution.part().to_string(),
+                    report.solution.variant().to_string(),
+                    answer,
+                    median,
+                    status,
+                ]
+            })
+            .collect();
+
+        let mut widths = HEADER.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let write_row = |f: &mut std::fmt::Formatter<'_>, row: [&str; 6]| {
+            let [day, part, variant, answer, median, status] = row;
+            writeln!(
+                f,
+                "{day:>w0$}  {part:>w1$}  {variant:<w2$}  {answer:>w3$}  {median:>w4$}  {status}",
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+                w4 = widths[4],
+            )
+        };
+
+        write_row(f, HEADER)?;
+        for row in &rows {
+            write_row(f, row.each_ref().map(String::as_str))?;
+        }
+
+        let total: Duration = self
+            .reports
+            .iter()
+            .filter_map(|r| match &r.outcome {
+                Outcome::Solved(m) => Some(m.median),
+                _ => None,
+            })
+            .sum();
+        let solved = self
+            .reports
+            .iter()
+            .filter(|r| matches!(r.outcome, Outcome::Solved(_)))
+            .count();
+
+        write!(
+            f,
+            "Total: {} ({solved}/{} solved)",
+            format_duration(total),
+            rows.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::{Part, Solver};
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(21_300)), "21.3us");
+        assert_eq!(format_duration(Duration::from_micros(6_700)), "6.70ms");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50s");
+    }
+
+    #[test]
+    fn measured() {
+        let solver = Solver::new(1, Part::One, "test", |input| Ok(Answer::from(input.len())));
+        let options = Options {
+            warmup: 0,
+            repetitions: 3,
+            timeout: None,
+        };
+
+        let measurement = measure(&solver, "hello", &options).unwrap();
+        assert_eq!(measurement.answer, 5usize);
+    }
+
+    #[test]
+    fn timeouts() {
+        static QUICK: Solver = Solver::new(1, Part::One, "quick", |input| Ok(Answer::from(input)));
+        static SLOW: Solver = Solver::new(1, Part::Two, "slow", |_| {
+            std::thread::sleep(Duration::from_secs(5));
+            Ok(Answer::from(0))
+        });
+        static PANICS: Solver = Solver::new(2, Part::One, "panics", |_| panic!("oh no"));
+
+        let options = Options {
+            warmup: 1,
+            repetitions: 3,
+            timeout: Some(Duration::from_millis(100)),
+        };
+        let input: Arc<str> = Arc::from("in");
+
+        assert!(matches!(
+            measure_with_timeout(&QUICK, input.clone(), &options),
+            Outcome::Solved(Measurement { answer, .. }) if answer == "in"
+        ));
+        assert!(matches!(
+            measure_with_timeout(&SLOW, input.clone(), &options),
+            Outcome::TimedOut(_)
+        ));
+        assert!(matches!(
+            measure_with_timeout(&PANICS, input, &options),
+            Outcome::Failed(Error::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn failing() {
+        static SOLVER: Solver = Solver::new(3, Part::Two, "test", |_| Ok(Answer::from(7)));
+        let answers = Answers::parse("[day3]\npart2 = 7").unwrap();
+        let report = |outcome| Report {
+            solution: &SOLVER,
+            source: None,
+            outcome,
+        };
+        let solved = |answer: u32| {
+            report(Outcome::Solved(Measurement {
+                answer: answer.into(),
+                median: Duration::ZERO,
+            }))
+        };
+
+        assert!(!any_failing(&[solved(7)], &answers));
+        assert!(any_failing(&[solved(7), solved(8)], &answers));
+        assert!(any_failing(
+            &[report(Outcome::Failed(Error::Failed("no".into())))],
+            &answers
+        ));
+        assert!(any_failing(
+            &[report(Outcome::TimedOut(Duration::from_secs(1)))],
+            &answers
+        ));
+    }
+
+    #[test]
+    fn json() {
+        static SOLVER: Solver = Solver::new(3, Part::Two, "test", |_| Ok(Answer::from(7)));
+        let report = Report {
+            solution: &SOLVER,
+            source: Some(Source {
+                path: "inputs/three.txt".into(),
+                hash: 0xabc,
+            }),
+            outcome: Outcome::Solved(Measurement {
+                answer: Answer::from(7),
+                median: Duration::from_micros(2),
+            }),
+        };
+
+        let answers = Answers::parse("[day3]\npart2 = 8").unwrap();
+        assert_eq!(
+            report.to_json(Some(&answers)).to_string(),
+            concat!(
+                r#"{"day":3,"part":2,"variant":"test","answer":7,"elapsed_ns":2000,"#,
+                r#""input":"inputs/three.txt","input_hash":"0000000000000abc","#,
+                r#""verdict":"wrong","expected":8,"error":null}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    Error,
+    days::{self, Part},
+    input,
+};
+use std::path::{Path, PathBuf};
+
+/// The source of a new day's module, with stubbed out parts
+/// and tests for its example.
+pub fn module(day: usize, name: &str) -> String {
+    format!(
+        r#"use crate::{{
+    Answer, Error,
+    days::{{Part, Solution, Solver}},
+}};
+
+pub(crate) const SOLVERS: &[&dyn Solution] = &[
+    &Solver::new({day}, Part::One, "default", part_one),
+    &Solver::new({day}, Part::Two, "default", part_two),
+];
+
+pub fn part_one(_input: &str) -> Result<Answer, Error> {{
+    Err(Error::Failed("not solved yet".into()))
+}}
+
+pub fn part_two(_input: &str) -> Result<Answer, Error> {{
+    Err(Error::Failed("not solved yet".into()))
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    #[test]
+    #[ignore = "fill in the example's answer"]
+    fn {name}() {{
+        let input = include_str!("../../inputs/{name}_test.txt");
+        assert_eq!(part_one(input).unwrap(), 0);
+    }}
+
+    #[test]
+    #[ignore = "fill in the example's answer"]
+    fn {name}2() {{
+        let input = include_str!("../../inputs/{name}_test.txt");
+        assert_eq!(part_two(input).unwrap(), 0);
+    }}
+}}
+"#
+    )
+}
+
+/// Add a module to the source of `days/mod.rs`, declaring it
+/// and registering its solvers.
+///
+/// Returns `None` if it's already declared, or the file
+/// doesn't have the expected shape.
+pub fn register(mod_rs: &str, name: &str) -> Option<String> {
+    let declaration = format!("pub mod {name};");
+    let mut lines: Vec<&str> = mod_rs.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return None;
+    }
+
+    // declarations are sorted alphabetically
+    let declared = lines
+        .iter()
+        .take_while(|line| line.starts_with("pub mod "))
+        .count();
+    let position = lines[..declared]
+        .iter()
+        .position(|line| *line > declaration.as_str())
+        .unwrap_or(declared);
+    lines.insert(position, &declaration);
+
+    // solvers are registered in order of day
+    let day_of = |entry: &str| {
+        let module = entry.trim().split("::").next()?;
+        (1..=days::LAST_DAY).find(|day| days::name(*day) == Some(module))
+    };
+    let day = day_of(name)?;
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("const MODULES"))?;
+    let end = start + lines[start..].iter().position(|line| *line == "];")?;
+    let position = (start + 1..end)
+        .find(|i| day_of(lines[*i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+
+    let registration = format!("    {name}::SOLVERS,");
+    lines.insert(position, &registration);
+
+    let mut registered = lines.join("\n");
+    registered.push('\n');
+    Some(registered)
+}
+
+/// Create a new day under the crate at `root`, returning the files written.
+///
+/// This writes `src/days/<name>.rs`, registers it in `src/days/mod.rs`
+/// and creates an empty example input if there isn't one. The CLI and
+/// benches find the new day through the registry.
+pub fn new_day(root: &Path, day: usize) -> Result<Vec<PathBuf>, Error> {
+    let Some(name) = days::name(day) else {
+        return Err(Error::Unsolved {
+            day,
+            part: Part::One,
+            variant: None,
+        });
+    };
+
+    let days_dir = root.join("src").join("days");
+    let module_path = days_dir.join(format!("{name}.rs"));
+    for existing in [&module_path, &days_dir.join(name)] {
+        if existing.exists() {
+            return Err(Error::Usage(format!(
+                "day {day} already exists at {}",
+                existing.display()
+            )));
+        }
+    }
+
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| Error::Io { path, source }
+    };
+
+    let mod_path = days_dir.join("mod.rs");
+    let mod_rs = std::fs::read_to_string(&mod_path).map_err(io_error(&mod_path))?;
+    let registered = register(&mod_rs, name).ok_or_else(|| {
+        Error::Usage(format!(
+            "couldn't register `{name}` in {}",
+            mod_path.display()
+        ))
+    })?;
+
+    std::fs::write(&module_path, module(day, name)).map_err(io_error(&module_path))?;
+    std::fs::write(&mod_path, registered).map_err(io_error(&mod_path))?;
+    let mut written = vec![module_path, mod_path];
+
+    let inputs = root.join(input::DEFAULT_DIR);
+    let example = inputs.join(input::file_name(day, true).expect("the day is valid"));
+    if !example.exists() {
+        std::fs::create_dir_all(&inputs).map_err(io_error(&inputs))?;
+        std::fs::write(&example, "").map_err(io_error(&example))?;
+        written.push(example);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MOD_RS: &str = "pub mod eleven;\npub mod six;\npub mod two;\n\n\
+        const MODULES: &[&[&dyn Solution]] = &[\n    \
+        two::SOLVERS,\n    six::second_try::SOLVERS,\n    eleven::SOLVERS,\n];\n";
+
+    #[test]
+    fn registered() {
+        let registered = register(MOD_RS, "seven").unwrap();
+        assert_eq!(
+            registered,
+            "pub mod eleven;\npub mod seven;\npub mod six;\npub mod two;\n\n\
+            const MODULES: &[&[&dyn Solution]] = &[\n    two::SOLVERS,\n    \
+            six::second_try::SOLVERS,\n    seven::SOLVERS,\n    eleven::SOLVERS,\n];\n"
+        );
+
+        let registered = register(&registered, "twelve").unwrap();
+        assert!(registered.contains("pub mod twelve;\npub mod two;\n"));
+        assert!(registered.contains("    eleven::SOLVERS,\n    twelve::SOLVERS,\n];"));
+        assert!(register(&registered, "twelve").is_none());
+    }
+
+    #[test]
+    fn scaffolded() {
+        let root = std::env::temp_dir().join(format!("aoc-2025-new-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::write(root.join("src/days/mod.rs"), MOD_RS).unwrap();
+
+        let written = new_day(&root, 12).unwrap();
+        assert_eq!(written.len(), 3);
+        let module = std::fs::read_to_string(root.join("src/days/twelve.rs")).unwrap();
+        assert!(module.contains("Solver::new(12, Part::Two"));
+        assert!(module.contains("inputs/twelve_test.txt"));
+
+        assert!(matches!(new_day(&root, 12), Err(Error::Usage(_))));
+        assert!(matches!(new_day(&root, 13), Err(Error::Unsolved { .. })));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// How [`sum`] shares out its work.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// How many worker threads to run at most.
+    pub threads: usize,
+    /// How many integers each piece of work covers. Smaller pieces
+    /// balance better, larger ones cost less to hand out.
+    pub chunk: usize,
+}
+
+impl Default for Options {
+    /// A thread for every core, taking a few thousand integers at a time.
+    fn default() -> Self {
+        Self {
+            threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            chunk: 4096,
+        }
+    }
+}
+
+/// Add up the integers in the inclusive `ranges` that `keep` accepts,
+/// on a pool of worker threads.
+///
+/// The ranges are split into chunks of equal size, which the workers
+/// take one at a time, so one long range doesn't keep a single thread
+/// busy while the rest sit idle. Each worker makes its own scratch
+/// space with `state`, which is passed to every call of `keep`.
+pub fn sum<S>(
+    ranges: &[(usize, usize)],
+    options: Options,
+    state: impl Fn() -> S + Sync,
+    keep: impl Fn(&mut S, usize) -> bool + Sync,
+) -> u128 {
+    let (chunk, firsts, chunks) = split(ranges, options.chunk.max(1));
+
+    let next = AtomicUsize::new(0);
+    let work = || {
+        let mut state = state();
+        let mut sum = 0;
+
+        loop {
+            let index = next.fetch_add(1, Ordering::Relaxed);
+            if index >= chunks {
+                return sum;
+            }
+
+            // empty ranges share their first chunk with the next range,
+            // so take the last range starting here
+            let range = firsts.partition_point(|first| *first <= index) - 1;
+            let (start, end) = ranges[range];
+            let low = start + (index - firsts[range]) * chunk;
+            let high = end.min(low.saturating_add(chunk - 1));
+
+            for i in low..=high {
+                if keep(&mut state, i) {
+                    sum += i as u128;
+                }
+            }
+        }
+    };
+
+    let threads = options.threads.clamp(1, chunks.max(1));
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|_| scope.spawn(work)).collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .sum()
+    })
+}
+
+/// Split `ranges` into chunks of `chunk` integers, returning the chunk
+/// size used, the index of each range's first chunk, counting through
+/// them all, and how many chunks there are.
+///
+/// If there would be too many chunks to count, the chunks are made
+/// bigger until there aren't.
+fn split(ranges: &[(usize, usize)], chunk: usize) -> (usize, Vec<usize>, usize) {
+    let mut firsts = Vec::with_capacity(ranges.len());
+    let mut chunks = 0usize;
+    for (start, end) in ranges {
+        firsts.push(chunks);
+        if start <= end {
+            let count = chunks
+                .checked_add((end - start) / chunk)
+                .and_then(|count| count.checked_add(1));
+            match count {
+                Some(count) => chunks = count,
+                None => return split(ranges, chunk.saturating_mul(2)),
+            }
+        }
+    }
+    (chunk, firsts, chunks)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn summed() {
+        let ranges = [(1, 100), (50, 40), (7, 7), (1000, 12345), (0, 0)];
+        let expected: u128 = ranges
+            .iter()
+            .flat_map(|(start, end)| *start..=*end)
+            .filter(|i| i % 3 == 0)
+            .map(|i| i as u128)
+            .sum();
+
+        for (threads, chunk) in [(1, 4096), (3, 1), (4, 7), (64, 100), (0, 0)] {
+            let options = Options { threads, chunk };
+            let total = sum(&ranges, options, || (), |_, i| i % 3 == 0);
+            assert_eq!(total, expected, "{options:?}");
+        }
+
+        assert_eq!(sum(&[], Options::default(), || (), |_, _| true), 0);
+        assert_eq!(
+            sum(
+                &[(usize::MAX - 2, usize::MAX)],
+                Options::default(),
+                || (),
+                |_, _| true
+            ),
+            3 * usize::MAX as u128 - 3
+        );
+    }
+
+    #[test]
+    fn split_everything() {
+        let (chunk, firsts, chunks) = split(&[(0, usize::MAX)], 1);
+        assert_eq!((chunk, firsts, chunks), (2, vec![0], usize::MAX / 2 + 1));
+
+        let (chunk, firsts, chunks) = split(&[(5, 9), (0, usize::MAX)], 1);
+        assert_eq!((chunk, firsts), (2, vec![0, 3]));
+        assert_eq!(chunks, usize::MAX / 2 + 4);
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{Answer, Error, days::Part};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// What the server said about a rejected answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Hint {
+    fn parse(text: &str) -> Option<Self> {
+        match text {
+            "too high" => Some(Self::TooHigh),
+            "too low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// The server's reply to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    /// The answer was wrong, and no more may be submitted for `wait`.
+    Wrong {
+        hint: Hint,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, so this one wasn't checked.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part was already solved, so this one wasn't checked.
+    AlreadySolved,
+    /// Anything else, with the reply's text.
+    Unknown(String),
+}
+
+impl Reply {
+    /// Understand the HTML page returned after submitting.
+    pub fn parse(html: &str) -> Self {
+        let text = message(html);
+        let lower = text.to_lowercase();
+
+        if lower.contains("that's the right answer") {
+            Self::Correct
+        } else if lower.contains("that's not the right answer") {
+            let hint = if lower.contains("too high") {
+                Hint::TooHigh
+            } else if lower.contains("too low") {
+                Hint::TooLow
+            } else {
+                Hint::Wrong
+            };
+            Self::Wrong {
+                hint,
+                wait: penalty(&lower),
+            }
+        } else if lower.contains("answer too recently") {
+            Self::TooSoon {
+                wait: time_left(&lower).unwrap_or(Duration::from_secs(60)),
+            }
+        } else if lower.contains("solving the right level") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(text)
+        }
+    }
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::Wrong { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                if *hint != Hint::Wrong {
+                    write!(f, ", it's {hint}")?;
+                }
+                if let Some(wait) = wait {
+                    write!(f, ". Wait {}s before trying again", wait.as_secs())?;
+                }
+                Ok(())
+            }
+            Self::TooSoon { wait } => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            Self::AlreadySolved => write!(f, "This part is already solved"),
+            Self::Unknown(text) => write!(f, "Unexpected reply: {text}"),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, without any tags.
+fn message(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(html, |(_, rest)| rest);
+    let article = article.split("</article>").next().unwrap_or_default();
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The wait after a wrong answer, like "please wait one minute".
+fn penalty(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let count = match words.next()? {
+        "one" | "a" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        number => number.parse().ok()?,
+    };
+
+    match words.next()? {
+        unit if unit.starts_with("second") => Some(Duration::from_secs(count)),
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(count * 60)),
+        unit if unit.starts_with("hour") => Some(Duration::from_secs(count * 3600)),
+        _ => None,
+    }
+}
+
+/// The wait when answering too soon, like "you have 1m 30s left to wait".
+fn time_left(text: &str) -> Option<Duration> {
+    let (_, rest) = text.rsplit_once("you have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+
+    left.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "s" => Some(number),
+                "m" => Some(number * 60),
+                "h" => Some(number * 3600),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Answers the server rejected, and when the next may be submitted.
+///
+/// These are stored in a small subset of TOML:
+///
+/// ```toml
+/// wait_until = 1764572400
+///
+/// [day1.part1]
+/// "1200" = "too high"
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Submissions {
+    /// When answers may be submitted again, in seconds since the epoch.
+    wait_until: Option<u64>,
+    rejected: BTreeMap<(usize, Part), Vec<(Answer, Hint)>>,
+}
+
+impl Submissions {
+    /// Load submissions from `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| match e {
+                Error::InvalidSubmissions { line, text, .. } => Error::InvalidSubmissions {
+                    path: Some(path.to_owned()),
+                    line,
+                    text,
+                },
+                e => e,
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut submissions = Self::default();
+        let mut section = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let invalid = || Error::InvalidSubmissions {
+                path: None,
+                line: i + 1,
+                text: line.to_string(),
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let (day, part) = header
+                    .strip_suffix(']')
+                    .and_then(|h| h.strip_prefix("day"))
+                    .and_then(|h| h.split_once(".part"))
+                    .ok_or_else(invalid)?;
+                let part = match part {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err(invalid()),
+                };
+                section = Some((day.parse().map_err(|_| invalid())?, part));
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            let (key, value) = (key.trim(), value.trim());
+            if key == "wait_until" && section.is_none() {
+                submissions.wait_until = Some(value.parse().map_err(|_| invalid())?);
+                continue;
+            }
+
+            let answer = key
+                .strip_prefix('"')
+                .and_then(|k| k.strip_suffix('"'))
+                .ok_or_else(invalid)?;
+            let hint = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .and_then(Hint::parse)
+                .ok_or_else(invalid)?;
+
+            submissions
+                .rejected
+                .entry(section.ok_or_else(invalid)?)
+                .or_default()
+                .push((Answer::parse(answer), hint));
+        }
+
+        Ok(submissions)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        std::fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    /// How long until another answer may be submitted.
+    pub fn wait(&self, now: SystemTime) -> Option<Duration> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+        Duration::from_secs(self.wait_until?)
+            .checked_sub(now)
+            .filter(|wait| !wait.is_zero())
+    }
+
+    /// Remember that nothing may be submitted for `wait` after `now`.
+    pub fn wait_for(&mut self, now: SystemTime, wait: Duration) {
+        let until = now.duration_since(UNIX_EPOCH).unwrap_or_default() + wait;
+        self.wait_until = Some(until.as_secs_f64().ceil() as u64);
+    }
+
+    pub fn reject(&mut self, day: usize, part: Part, answer: Answer, hint: Hint) {
+        self.rejected
+            .entry((day, part))
+            .or_default()
+            .push((answer, hint));
+    }
+
+    /// Why `answer` is already known to be wrong, if it is.
+    ///
+    /// Besides answers that were rejected outright, numbers beyond
+    /// one that was too high or too low are refused.
+    pub fn known_wrong(&self, day: usize, part: Part, answer: &Answer) -> Option<String> {
+        let rejected = self.rejected.get(&(day, part))?;
+        if let Some((_, hint)) = rejected.iter().find(|(a, _)| a == answer) {
+            return Some(format!("`{answer}` was already rejected as {hint}"));
+        }
+
+        let number = answer.as_i128()?;
+        rejected.iter().find_map(|(rejected, hint)| {
+            let bound = rejected.as_i128()?;
+            match hint {
+                Hint::TooHigh if number > bound => {
+                    Some(format!("`{answer}` is above `{bound}`, which was too high"))
+                }
+                Hint::TooLow if number < bound => {
+                    Some(format!("`{answer}` is below `{bound}`, which was too low"))
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+impl Display for Submissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(wait_until) = self.wait_until {
+            writeln!(f, "wait_until = {wait_until}")?;
+        }
+
+        for ((day, part), rejected) in &self.rejected {
+            writeln!(f, "\n[day{day}.part{part}]")?;
+            for (answer, hint) in rejected {
+                writeln!(f, "\"{answer}\" = \"{hint}\"")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn replies() {
+        let wrong = "<main>\n<article><p>That's not the right answer; your answer is too high.  \
+            If you're stuck, make sure you're using the full input data. Please wait one minute \
+            before trying again. <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+        assert_eq!(
+            Reply::parse(wrong),
+            Reply::Wrong {
+                hint: Hint::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+
+        let soon = "<article><p>You gave an answer too recently; you have to wait after \
+            submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+        assert_eq!(
+            Reply::parse(soon),
+            Reply::TooSoon {
+                wait: Duration::from_secs(65)
+            }
+        );
+
+        assert_eq!(
+            Reply::parse(
+                "<article><p>That's the right answer!  You are one gold star closer.</p></article>"
+            ),
+            Reply::Correct
+        );
+        assert_eq!(
+            Reply::parse("<article><p>You don't seem to be solving the right level.</p></article>"),
+            Reply::AlreadySolved
+        );
+        assert_eq!(
+            Reply::parse("<article><p>Huh?</p></article>"),
+            Reply::Unknown("Huh?".into())
+        );
+    }
+
+    #[test]
+    fn remembered() {
+        let mut submissions = Submissions::default();
+        submissions.reject(1, Part::Two, Answer::from(100), Hint::TooHigh);
+        submissions.reject(1, Part::Two, Answer::from(10), Hint::TooLow);
+        submissions.reject(1, Part::Two, Answer::from(50), Hint::Wrong);
+
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        submissions.wait_for(now, Duration::from_secs(60));
+
+        let text = submissions.to_string();
+        let submissions = Submissions::parse(&text).unwrap();
+        assert_eq!(submissions.to_string(), text);
+
+        assert_eq!(submissions.wait(now), Some(Duration::from_secs(60)));
+        assert_eq!(submissions.wait(now + Duration::from_secs(60)), None);
+
+        for wrong in [100, 101, 9, 50] {
+            assert!(
+                submissions
+                    .known_wrong(1, Part::Two, &Answer::from(wrong))
+                    .is_some()
+            );
+        }
+        assert!(
+            submissions
+                .known_wrong(1, Part::Two, &Answer::from(42))
+                .is_none()
+        );
+        assert!(
+            submissions
+                .known_wrong(1, Part::One, &Answer::from(100))
+                .is_none()
+        );
+
+        assert!(matches!(
+            Submissions::parse(&format!("{text}nonsense\n")),
+            Err(Error::InvalidSubmissions { path: None, ref text, .. }) if text == "nonsense"
+        ));
+    }
+}
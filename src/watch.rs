@@ -0,0 +1,119 @@
+use crate::days;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// Polls files, and everything inside directories, for changes.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    stamps: BTreeMap<PathBuf, SystemTime>,
+    interval: Duration,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>, interval: Duration) -> Self {
+        let stamps = snapshot(&paths);
+        Self {
+            paths,
+            stamps,
+            interval,
+        }
+    }
+
+    /// Whether anything was modified, created or removed since the last check.
+    pub fn changed(&mut self) -> bool {
+        let stamps = snapshot(&self.paths);
+        if stamps == self.stamps {
+            return false;
+        }
+
+        self.stamps = stamps;
+        true
+    }
+
+    /// Block until something changes.
+    pub fn wait(&mut self) {
+        while !self.changed() {
+            std::thread::sleep(self.interval);
+        }
+
+        // editors often write in several steps, so let them finish
+        std::thread::sleep(self.interval);
+        self.stamps = snapshot(&self.paths);
+    }
+}
+
+/// The modification time of every file under `paths`.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    fn visit(path: &Path, stamps: &mut BTreeMap<PathBuf, SystemTime>) {
+        let Ok(metadata) = path.metadata() else {
+            return;
+        };
+
+        if metadata.is_dir() {
+            for entry in std::fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), stamps);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            stamps.insert(path.to_owned(), modified);
+        }
+    }
+
+    let mut stamps = BTreeMap::new();
+    for path in paths {
+        visit(path, &mut stamps);
+    }
+    stamps
+}
+
+/// The source files for a day, like `src/days/six.rs` or `src/days/six/`.
+pub fn sources(day: usize) -> Vec<PathBuf> {
+    let Some(name) = days::name(day) else {
+        return Vec::new();
+    };
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("days");
+    vec![dir.join(format!("{name}.rs")), dir.join(name)]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn changes() {
+        let dir = std::env::temp_dir().join(format!("aoc-2025-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        let file = dir.join("nested").join("input.txt");
+        std::fs::write(&file, "1").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.clone()], Duration::ZERO);
+        assert!(!watcher.changed());
+
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        std::fs::remove_file(&file).unwrap();
+        assert!(watcher.changed());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn day_sources() {
+        assert!(sources(1)[0].is_file());
+        assert!(sources(6)[1].is_dir());
+        assert!(sources(13).is_empty());
+    }
+}